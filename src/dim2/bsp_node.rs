@@ -26,11 +26,11 @@ impl BspNode {
 
     pub fn invert(&mut self) {
         for l in self.lines.iter_mut() {
-            l.flip();
+            *l = l.flip();
         }
 
-        if self.plane.is_some() {
-            self.plane.as_mut().unwrap().flip();
+        if let Some(plane) = &mut self.plane {
+            *plane = plane.flip();
         }
 
         if self.front.is_some() {
@@ -50,7 +50,7 @@ impl BspNode {
 
     pub fn clip_lines(&mut self, lines: &Vec<Line>) -> Vec<Line> {
         if self.plane.is_none() {
-            return self.lines.clone();
+            return lines.clone();
         }
 
         let mut front: Vec<Line> = Vec::new();
@@ -99,7 +99,7 @@ impl BspNode {
     }
 
     pub fn all_lines(&self) -> Vec<Line> {
-        let mut lines: Vec<Line> = self.lines.clone();
+        let mut lines: Vec<Line> = Vec::new();
         self.fill_lines(&mut lines);
         lines
     }
@@ -98,7 +98,7 @@ impl Csg {
     pub fn inverse(&self) -> Csg {
         let mut csg = self.clone();
         for line in csg.lines.iter_mut() {
-            line.flip();
+            *line = line.flip();
         }
         csg
     }
@@ -4,7 +4,9 @@ mod line;
 mod line_strip;
 mod plane;
 mod point;
+mod region;
 mod shapes;
+mod triangulate;
 
 pub use self::bsp_node::BspNode;
 pub use self::csg::Csg;
@@ -12,4 +14,6 @@ pub use self::line::Line;
 pub use self::line_strip::LineStrip;
 pub use self::plane::Plane;
 pub use self::point::Point;
+pub use self::region::{loop_contains, Region};
 pub use self::shapes::{circle, rectangle};
+pub use self::triangulate::{signed_area, triangulate};
@@ -1,4 +1,4 @@
-use dim2::{Line, LineStrip, Point};
+use dim2::{Line, Point};
 use {Unit, EPSILON};
 
 bitflags! {
@@ -56,7 +56,7 @@ impl Plane {
 
         match polygon_type {
             Location::COPLANAR => {
-                if self.0.dot(line.p0) > (0 as Unit) {
+                if self.0.dot(line.plane.0) > (0 as Unit) {
                     coplane_front.push(line);
                 } else {
                     coplane_back.push(line);
@@ -65,34 +65,22 @@ impl Plane {
             Location::FRONT => front.push(line),
             Location::BACK => back.push(line),
             Location::SPANNING => {
-                let mut f: Vec<Point> = Vec::new();
-                let mut b: Vec<Point> = Vec::new();
-
-                for (i, (p0, p1)) in [(line.p0, line.p1), (line.p1, line.p0)].iter().enumerate() {
-                    let j = (i + 1) & 0b1;
-                    if point_locs[i] != Location::BACK {
-                        f.push(line.p0);
-                    }
-
-                    if point_locs[i] != Location::FRONT {
-                        b.push(line.p0);
-                    }
-
-                    if (point_locs[i] | point_locs[j]) == Location::SPANNING {
-                        let t = (self.1 - self.0.dot(*p0)) / self.0.dot(*p1 - *p0);
-
-                        let v = p0.interpolate(p1, t);
-                        f.push(v);
-                        b.push(v);
-                    }
-                }
-
-                if f.len() >= 2 {
-                    front.append(&mut LineStrip::from_points(f).build_lines());
-                }
-
-                if b.len() >= 2 {
-                    back.append(&mut LineStrip::from_points(b).build_lines());
+                let t = (self.1 - self.0.dot(line.p0)) / self.0.dot(line.p1 - line.p0);
+                let v = line.p0.interpolate(&line.p1, t);
+
+                // Keep the plane of the original line, the pieces are collinear with it
+                let first = Line {
+                    p1: v,
+                    ..line.clone()
+                };
+                let second = Line { p0: v, ..line };
+
+                if point_locs[0] == Location::FRONT {
+                    front.push(first);
+                    back.push(second);
+                } else {
+                    back.push(first);
+                    front.push(second);
                 }
             }
             _ => (),
@@ -1,5 +1,5 @@
 use std::ops::{Add, Div, Mul, Neg, Sub};
-use Unit;
+use {IUnit, Unit};
 
 #[derive(Clone, Copy)]
 pub struct Point(pub Unit, pub Unit);
@@ -25,6 +25,14 @@ impl Point {
         *self / self.length()
     }
 
+    /// Snap to a grid with `step` cells per unit, for hashing.
+    pub fn discreet(&self, step: Unit) -> (IUnit, IUnit) {
+        (
+            (self.0 * step).round() as IUnit,
+            (self.1 * step).round() as IUnit,
+        )
+    }
+
     pub fn interpolate(&self, other: &Point, weight: f32) -> Point {
         *self + (*other - *self) * weight
     }
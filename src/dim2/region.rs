@@ -0,0 +1,234 @@
+use dim2::{signed_area, triangulate, Csg, Line, Point};
+use std::collections::HashMap;
+use {Unit, EPSILON};
+
+/// An area enclosed by one outer loop, minus the area of its holes. The outer loop is counter
+/// clockwise and the holes are clockwise.
+#[derive(Clone)]
+pub struct Region {
+    pub outer: Vec<Point>,
+    pub holes: Vec<Vec<Point>>,
+}
+
+/// Even-odd test of `p` against a closed loop.
+pub fn loop_contains(points: &[Point], p: Point) -> bool {
+    let len = points.len();
+    let mut inside = false;
+
+    for i in 0..len {
+        let (a, b) = (points[i], points[(i + 1) % len]);
+        if (a.1 > p.1) != (b.1 > p.1) && p.0 < a.0 + (p.1 - a.1) / (b.1 - a.1) * (b.0 - a.0) {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+fn segments_cross(a0: Point, a1: Point, b0: Point, b1: Point) -> bool {
+    fn side(o: Point, a: Point, b: Point) -> Unit {
+        let (a, b) = (a - o, b - o);
+        a.0 * b.1 - a.1 * b.0
+    }
+
+    side(a0, a1, b0) * side(a0, a1, b1) < 0. && side(b0, b1, a0) * side(b0, b1, a1) < 0.
+}
+
+/// Remove corners lying on the line between their neighbours.
+fn remove_collinear(points: &mut Vec<Point>) {
+    let mut i = 0;
+    while points.len() > 3 && i < points.len() {
+        let len = points.len();
+        let (a, b, c) = (
+            points[(i + len - 1) % len],
+            points[i],
+            points[(i + 1) % len],
+        );
+        let (ab, bc) = (b - a, c - b);
+
+        if (ab.0 * bc.1 - ab.1 * bc.0).abs() <= EPSILON * ab.length().max(bc.length())
+            && ab.dot(bc) > 0.
+        {
+            points.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+impl Region {
+    /// Split the region into triangles. Holes are joined with the outer loop by bridge edges
+    /// first, so the returned points may contain duplicates. Triangles are counter clockwise.
+    pub fn triangulate(&self) -> (Vec<Point>, Vec<[usize; 3]>) {
+        let mut outline = self.outer.clone();
+
+        // Bridge the holes with their rightmost point first, so later bridges can't be blocked by
+        // holes which are not yet part of the outline
+        let mut holes: Vec<&Vec<Point>> = self.holes.iter().filter(|h| h.len() >= 3).collect();
+        holes.sort_by(|a, b| {
+            let max_x = |h: &Vec<Point>| h.iter().fold(Unit::MIN, |m, p| m.max(p.0));
+            max_x(b).partial_cmp(&max_x(a)).unwrap()
+        });
+
+        for (n, hole) in holes.iter().enumerate() {
+            let (m, hole_point) = hole.iter().enumerate().fold((0, hole[0]), |best, (i, p)| {
+                if p.0 > best.1 .0 {
+                    (i, *p)
+                } else {
+                    best
+                }
+            });
+
+            // Closest outline corner that can be reached without crossing any edge
+            let blocked = |target: Point| -> bool {
+                let crosses = |points: &[Point]| {
+                    let len = points.len();
+                    (0..len).any(|i| {
+                        segments_cross(hole_point, target, points[i], points[(i + 1) % len])
+                    })
+                };
+
+                crosses(&outline) || holes[n..].iter().any(|h| crosses(h))
+            };
+
+            let bridge = (0..outline.len())
+                .filter(|&i| !blocked(outline[i]))
+                .min_by(|&i, &j| {
+                    let di = (outline[i] - hole_point).length();
+                    let dj = (outline[j] - hole_point).length();
+                    di.partial_cmp(&dj).unwrap()
+                });
+
+            if let Some(i) = bridge {
+                let mut joined: Vec<Point> = outline[..(i + 1)].to_vec();
+                joined.extend((0..(hole.len() + 1)).map(|k| hole[(m + k) % hole.len()]));
+                joined.extend_from_slice(&outline[i..]);
+                outline = joined;
+            }
+        }
+
+        let triangles = triangulate(&outline);
+        (outline, triangles)
+    }
+}
+
+impl Csg {
+    /// Chain the lines into closed loops by matching end points with start points. Lines that do
+    /// not form a closed loop are ignored.
+    pub fn to_loops(&self) -> Vec<Vec<Point>> {
+        let step = 1. / EPSILON;
+        let mut starts: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (i, line) in self.lines.iter().enumerate() {
+            starts.entry(line.p0.discreet(step)).or_default().push(i);
+        }
+
+        let mut used = vec![false; self.lines.len()];
+        let mut loops = Vec::new();
+
+        for first in 0..self.lines.len() {
+            if used[first] {
+                continue;
+            }
+
+            let start_key = self.lines[first].p0.discreet(step);
+            let mut points = Vec::new();
+            let mut current = first;
+            let mut closed = false;
+
+            loop {
+                used[current] = true;
+                points.push(self.lines[current].p0);
+
+                let end_key = self.lines[current].p1.discreet(step);
+                if end_key == start_key {
+                    closed = true;
+                    break;
+                }
+
+                let next = starts
+                    .get(&end_key)
+                    .and_then(|candidates| candidates.iter().find(|&&i| !used[i]));
+
+                match next {
+                    Some(&next) => current = next,
+                    None => break,
+                }
+            }
+
+            if closed && points.len() >= 3 {
+                loops.push(points);
+            }
+        }
+
+        loops
+    }
+
+    /// Chain the lines into closed loops and join lines that continue in the same direction.
+    /// Lines that do not form a closed loop are dropped.
+    pub fn simplify(&self) -> Csg {
+        let mut lines = Vec::new();
+
+        for mut outline in self.to_loops() {
+            remove_collinear(&mut outline);
+            let len = outline.len();
+            for i in 0..len {
+                lines.push(Line::new(outline[i], outline[(i + 1) % len]));
+            }
+        }
+
+        Csg::from_lines(lines)
+    }
+
+    /// Group the closed loops into regions with holes, by how deeply each loop is nested inside
+    /// the others. Loops are reoriented as needed, so the direction of the lines does not matter.
+    pub fn to_regions(&self) -> Vec<Region> {
+        let mut loops: Vec<Vec<Point>> = self
+            .to_loops()
+            .into_iter()
+            .filter(|l| signed_area(l).abs() > EPSILON * EPSILON)
+            .collect();
+
+        // Largest first, so a parent always comes before the loops inside of it
+        loops.sort_by(|a, b| {
+            signed_area(b)
+                .abs()
+                .partial_cmp(&signed_area(a).abs())
+                .unwrap()
+        });
+
+        let mut depths: Vec<usize> = Vec::with_capacity(loops.len());
+        let mut regions: Vec<Region> = Vec::new();
+        let mut region_of: Vec<usize> = Vec::with_capacity(loops.len());
+
+        for i in 0..loops.len() {
+            let probe = loops[i][0];
+            let parent = (0..i).rev().find(|&j| loop_contains(&loops[j], probe));
+            let depth = parent.map_or(0, |j| depths[j] + 1);
+
+            let mut points = loops[i].clone();
+            let ccw = signed_area(&points) > 0.;
+
+            if depth % 2 == 0 {
+                if !ccw {
+                    points.reverse();
+                }
+                regions.push(Region {
+                    outer: points,
+                    holes: Vec::new(),
+                });
+                region_of.push(regions.len() - 1);
+            } else {
+                if ccw {
+                    points.reverse();
+                }
+                let region = region_of[parent.unwrap()];
+                regions[region].holes.push(points);
+                region_of.push(region);
+            }
+
+            depths.push(depth);
+        }
+
+        regions
+    }
+}
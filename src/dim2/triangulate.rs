@@ -0,0 +1,112 @@
+use dim2::Point;
+use Unit;
+
+fn cross(o: Point, a: Point, b: Point) -> Unit {
+    let (a, b) = (a - o, b - o);
+    a.0 * b.1 - a.1 * b.0
+}
+
+fn same(a: Point, b: Point) -> bool {
+    a.0 == b.0 && a.1 == b.1
+}
+
+/// Twice the signed area of a closed loop, positive when counter clockwise.
+pub fn signed_area(points: &[Point]) -> Unit {
+    let len = points.len();
+    (0..len)
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % len]);
+            a.0 * b.1 - b.0 * a.1
+        })
+        .sum()
+}
+
+/// Split a simple polygon into triangles using ear clipping. Returns indices into `points`, with
+/// triangles wound the same way as the polygon. Works for non-convex polygons of either
+/// orientation.
+pub fn triangulate(points: &[Point]) -> Vec<[usize; 3]> {
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    let mut triangles = Vec::new();
+    let orientation = signed_area(points).signum();
+
+    let is_ear = |remaining: &Vec<usize>, i: usize, check_inside: bool| -> bool {
+        let len = remaining.len();
+        let (ia, ib, ic) = (
+            remaining[(i + len - 1) % len],
+            remaining[i],
+            remaining[(i + 1) % len],
+        );
+        let (a, b, c) = (points[ia], points[ib], points[ic]);
+
+        if cross(a, b, c) * orientation <= 0. {
+            return false;
+        }
+
+        !check_inside
+            || remaining.iter().all(|&j| {
+                let p = points[j];
+                j == ia
+                    || j == ib
+                    || j == ic
+                    || same(p, a)
+                    || same(p, b)
+                    || same(p, c)
+                    || cross(a, b, p) * orientation < 0.
+                    || cross(b, c, p) * orientation < 0.
+                    || cross(c, a, p) * orientation < 0.
+            })
+    };
+
+    while remaining.len() > 3 {
+        let len = remaining.len();
+
+        // Prefer proper ears, then drop corners without area, and as a last resort (self
+        // intersecting input) cut any convex corner to make progress.
+        let found = (0..len)
+            .find(|&i| is_ear(&remaining, i, true))
+            .map(|i| (i, true))
+            .or_else(|| {
+                (0..len)
+                    .find(|&i| {
+                        let (a, b, c) = (
+                            points[remaining[(i + len - 1) % len]],
+                            points[remaining[i]],
+                            points[remaining[(i + 1) % len]],
+                        );
+                        cross(a, b, c) == 0.
+                    })
+                    .map(|i| (i, false))
+            })
+            .or_else(|| {
+                (0..len)
+                    .find(|&i| is_ear(&remaining, i, false))
+                    .map(|i| (i, true))
+            });
+
+        match found {
+            Some((i, emit)) => {
+                if emit {
+                    triangles.push([
+                        remaining[(i + len - 1) % len],
+                        remaining[i],
+                        remaining[(i + 1) % len],
+                    ]);
+                }
+                remaining.remove(i);
+            }
+            None => return triangles,
+        }
+    }
+
+    if remaining.len() == 3
+        && cross(
+            points[remaining[0]],
+            points[remaining[1]],
+            points[remaining[2]],
+        ) != 0.
+    {
+        triangles.push([remaining[0], remaining[1], remaining[2]]);
+    }
+
+    triangles
+}
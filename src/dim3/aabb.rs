@@ -0,0 +1,148 @@
+use dim3::{Csg, IVector, Vector};
+use Unit;
+
+/// Axis aligned bounding box. A box that contains nothing has `min` larger than `max`.
+#[derive(Clone, Copy, Debug)]
+pub struct Aabb {
+    pub min: Vector,
+    pub max: Vector,
+}
+
+impl Aabb {
+    pub fn new(min: Vector, max: Vector) -> Aabb {
+        Aabb { min, max }
+    }
+
+    /// Box containing nothing, which any point stretches to just that point.
+    pub fn empty() -> Aabb {
+        Aabb::new(
+            Vector(Unit::MAX, Unit::MAX, Unit::MAX),
+            Vector(Unit::MIN, Unit::MIN, Unit::MIN),
+        )
+    }
+
+    pub fn from_points<I>(points: I) -> Aabb
+    where
+        I: IntoIterator<Item = Vector>,
+    {
+        let mut aabb = Aabb::empty();
+        for p in points {
+            aabb.stretch(p);
+        }
+        aabb
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.0 > self.max.0 || self.min.1 > self.max.1 || self.min.2 > self.max.2
+    }
+
+    /// Grow the box to include `v`.
+    pub fn stretch(&mut self, v: Vector) {
+        self.min = Vector(
+            self.min.0.min(v.0),
+            self.min.1.min(v.1),
+            self.min.2.min(v.2),
+        );
+        self.max = Vector(
+            self.max.0.max(v.0),
+            self.max.1.max(v.1),
+            self.max.2.max(v.2),
+        );
+    }
+
+    /// Box grown by `margin` in every direction, or shrunk for a negative margin.
+    pub fn expand(&self, margin: Unit) -> Aabb {
+        let m = Vector(margin, margin, margin);
+        Aabb::new(self.min - m, self.max + m)
+    }
+
+    pub fn union(a: &Aabb, b: &Aabb) -> Aabb {
+        Aabb::new(
+            Vector(
+                a.min.0.min(b.min.0),
+                a.min.1.min(b.min.1),
+                a.min.2.min(b.min.2),
+            ),
+            Vector(
+                a.max.0.max(b.max.0),
+                a.max.1.max(b.max.1),
+                a.max.2.max(b.max.2),
+            ),
+        )
+    }
+
+    /// The common part of two boxes, which is empty if they do not overlap.
+    pub fn intersection(a: &Aabb, b: &Aabb) -> Aabb {
+        Aabb::new(
+            Vector(
+                a.min.0.max(b.min.0),
+                a.min.1.max(b.min.1),
+                a.min.2.max(b.min.2),
+            ),
+            Vector(
+                a.max.0.min(b.max.0),
+                a.max.1.min(b.max.1),
+                a.max.2.min(b.max.2),
+            ),
+        )
+    }
+
+    /// True if the boxes overlap or touch.
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        !Aabb::intersection(self, other).is_empty()
+    }
+
+    /// True if `v` is inside or on the border of the box.
+    pub fn contains(&self, v: Vector) -> bool {
+        v.0 >= self.min.0
+            && v.0 <= self.max.0
+            && v.1 >= self.min.1
+            && v.1 <= self.max.1
+            && v.2 >= self.min.2
+            && v.2 <= self.max.2
+    }
+
+    /// Distance from `v` to the closest point of the box, zero if `v` is inside.
+    pub fn distance(&self, v: Vector) -> Unit {
+        let outside = |x: Unit, min: Unit, max: Unit| (min - x).max(x - max).max(0.);
+        Vector(
+            outside(v.0, self.min.0, self.max.0),
+            outside(v.1, self.min.1, self.max.1),
+            outside(v.2, self.min.2, self.max.2),
+        )
+        .length()
+    }
+
+    pub fn size(&self) -> Vector {
+        self.max - self.min
+    }
+
+    pub fn center(&self) -> Vector {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Area of the sides of the box, zero for an empty box.
+    pub fn surface_area(&self) -> Unit {
+        if self.is_empty() {
+            return 0.;
+        }
+        let s = self.size();
+        2. * (s.0 * s.1 + s.0 * s.2 + s.1 * s.2)
+    }
+
+    /// Corners snapped to a grid with `div` cells per unit.
+    pub fn get_min_max_discreet(&self, div: Unit) -> (IVector, IVector) {
+        (self.min.discreet(div), self.max.discreet(div))
+    }
+}
+
+impl Csg {
+    /// Smallest box containing every vertex, empty if there are no polygons.
+    pub fn bounding_box(&self) -> Aabb {
+        Aabb::from_points(
+            self.polygons
+                .iter()
+                .flat_map(|p| p.vertices.iter().map(|v| v.position)),
+        )
+    }
+}
@@ -0,0 +1,308 @@
+use dim3::{Aabb, Csg, Polygon, Vector};
+use Unit;
+
+/// Polygons per leaf, below which nodes are not split further.
+const LEAF_SIZE: usize = 4;
+
+/// Largest leaf kept when splitting does not pay off by the surface area heuristic.
+const MAX_LEAF_SIZE: usize = 16;
+
+enum BvhNode {
+    Leaf {
+        aabb: Aabb,
+        /// Range in `Bvh::order`.
+        first: usize,
+        count: usize,
+    },
+    Split {
+        aabb: Aabb,
+        left: usize,
+        right: usize,
+    },
+}
+
+impl BvhNode {
+    fn aabb(&self) -> &Aabb {
+        match self {
+            BvhNode::Leaf { aabb, .. } | BvhNode::Split { aabb, .. } => aabb,
+        }
+    }
+}
+
+/// Bounding volume hierarchy over the polygons of a `Csg`, for answering many spatial queries
+/// on the same solid. Polygons are referred to by their index in `Csg::polygons`.
+///
+/// The tree is split by the surface area heuristic, which keeps the boxes that rays and queries
+/// have to visit small.
+pub struct Bvh {
+    polygons: Vec<Polygon>,
+    boxes: Vec<Aabb>,
+    /// Polygon indices, grouped so that every leaf covers a range.
+    order: Vec<usize>,
+    nodes: Vec<BvhNode>,
+}
+
+/// Entry and exit distance of a ray through a box, if it passes through it.
+fn ray_box(aabb: &Aabb, origin: Vector, inv_dir: Vector) -> Option<(Unit, Unit)> {
+    let mut near: Unit = 0.;
+    let mut far = Unit::MAX;
+
+    for &(o, inv, min, max) in &[
+        (origin.0, inv_dir.0, aabb.min.0, aabb.max.0),
+        (origin.1, inv_dir.1, aabb.min.1, aabb.max.1),
+        (origin.2, inv_dir.2, aabb.min.2, aabb.max.2),
+    ] {
+        if inv.is_infinite() {
+            // Parallel to the slab, the ray is either always or never between its sides
+            if o < min || o > max {
+                return None;
+            }
+            continue;
+        }
+
+        let (t0, t1) = ((min - o) * inv, (max - o) * inv);
+        near = near.max(t0.min(t1));
+        far = far.min(t0.max(t1));
+    }
+
+    if near <= far {
+        Some((near, far))
+    } else {
+        None
+    }
+}
+
+impl Bvh {
+    pub fn new(csg: &Csg) -> Bvh {
+        let polygons = csg.polygons.clone();
+        let boxes: Vec<Aabb> = polygons
+            .iter()
+            .map(|p| Aabb::from_points(p.vertices.iter().map(|v| v.position)))
+            .collect();
+
+        let mut bvh = Bvh {
+            order: (0..polygons.len()).collect(),
+            polygons,
+            boxes,
+            nodes: Vec::new(),
+        };
+        if !bvh.polygons.is_empty() {
+            let count = bvh.order.len();
+            bvh.build(0, count);
+        }
+        bvh
+    }
+
+    /// The polygons the hierarchy was built from, in their original order.
+    pub fn polygons(&self) -> &[Polygon] {
+        &self.polygons
+    }
+
+    pub fn bounding_box(&self) -> Aabb {
+        self.nodes.first().map_or(Aabb::empty(), |n| *n.aabb())
+    }
+
+    /// Sort `order[first..first + count]` by polygon center along `axis`.
+    fn sort_along(&mut self, first: usize, count: usize, axis: usize) {
+        let boxes = &self.boxes;
+        let key = |i: usize| {
+            let c = boxes[i].center();
+            [c.0, c.1, c.2][axis]
+        };
+        self.order[first..first + count].sort_by(|&a, &b| key(a).partial_cmp(&key(b)).unwrap());
+    }
+
+    /// Cheapest split by the surface area heuristic, as the axis, the number of polygons going
+    /// to the first child, and the cost. The cost of a split is the area of each child times the
+    /// number of polygons in it.
+    fn best_split(&mut self, first: usize, count: usize) -> (usize, usize, Unit) {
+        let mut best = (0, count / 2, Unit::MAX);
+
+        for axis in 0..3 {
+            self.sort_along(first, count, axis);
+            let range = &self.order[first..first + count];
+
+            // Area of everything after each split point, sweeping from the end
+            let mut after = vec![0.; count];
+            let mut aabb = Aabb::empty();
+            for k in (1..count).rev() {
+                aabb = Aabb::union(&aabb, &self.boxes[range[k]]);
+                after[k] = aabb.surface_area();
+            }
+
+            let mut aabb = Aabb::empty();
+            for k in 1..count {
+                aabb = Aabb::union(&aabb, &self.boxes[range[k - 1]]);
+                let cost = aabb.surface_area() * k as Unit + after[k] * (count - k) as Unit;
+                if cost < best.2 {
+                    best = (axis, k, cost);
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Build the subtree over `order[first..first + count]` and return its node index.
+    fn build(&mut self, first: usize, count: usize) -> usize {
+        let aabb = self.order[first..first + count]
+            .iter()
+            .fold(Aabb::empty(), |acc, &i| Aabb::union(&acc, &self.boxes[i]));
+
+        let index = self.nodes.len();
+        self.nodes.push(BvhNode::Leaf { aabb, first, count });
+        if count <= LEAF_SIZE {
+            return index;
+        }
+
+        // Splitting costs about as much as testing one more polygon against the whole box
+        let (axis, split, cost) = self.best_split(first, count);
+        let leaf_cost = aabb.surface_area() * (count as Unit - 1.);
+        if cost >= leaf_cost && count <= MAX_LEAF_SIZE {
+            return index;
+        }
+
+        self.sort_along(first, count, axis);
+        let left = self.build(first, split);
+        let right = self.build(first + split, count - split);
+        self.nodes[index] = BvhNode::Split { aabb, left, right };
+        index
+    }
+
+    /// Call `func` with the index of every polygon whose box the ray passes through, before
+    /// distance `max_t`. `func` returns a new `max_t`, so closer hits can prune the search.
+    pub(crate) fn ray_candidates<F>(&self, origin: Vector, dir: Vector, max_t: Unit, mut func: F)
+    where
+        F: FnMut(usize) -> Unit,
+    {
+        if self.nodes.is_empty() {
+            return;
+        }
+
+        let inv_dir = Vector(1. / dir.0, 1. / dir.1, 1. / dir.2);
+        let mut max_t = max_t;
+        let mut stack = vec![0];
+
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            match ray_box(node.aabb(), origin, inv_dir) {
+                Some((near, _)) if near <= max_t => {}
+                _ => continue,
+            }
+
+            match *node {
+                BvhNode::Leaf { first, count, .. } => {
+                    for &i in &self.order[first..first + count] {
+                        max_t = max_t.min(func(i));
+                    }
+                }
+                BvhNode::Split { left, right, .. } => {
+                    // Visit the child the ray enters first, so closer hits prune the other
+                    let near = |i: usize| {
+                        ray_box(self.nodes[i].aabb(), origin, inv_dir).map_or(Unit::MAX, |t| t.0)
+                    };
+                    if near(left) <= near(right) {
+                        stack.push(right);
+                        stack.push(left);
+                    } else {
+                        stack.push(left);
+                        stack.push(right);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Indices of the polygons whose bounding box the ray from `origin` along `dir` passes
+    /// through. These are the only polygons the ray can hit.
+    pub fn query_ray(&self, origin: Vector, dir: Vector) -> Vec<usize> {
+        let inv_dir = Vector(1. / dir.0, 1. / dir.1, 1. / dir.2);
+        let mut found = Vec::new();
+        self.ray_candidates(origin, dir, Unit::MAX, |i| {
+            // Leaves are only checked as a whole, so also check the box of the polygon
+            if ray_box(&self.boxes[i], origin, inv_dir).is_some() {
+                found.push(i);
+            }
+            Unit::MAX
+        });
+        found
+    }
+
+    /// Indices of the polygons whose bounding box overlaps or touches `aabb`.
+    pub fn query_aabb(&self, aabb: &Aabb) -> Vec<usize> {
+        let mut found = Vec::new();
+        if self.nodes.is_empty() {
+            return found;
+        }
+
+        let mut stack = vec![0];
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if !node.aabb().intersects(aabb) {
+                continue;
+            }
+
+            match *node {
+                BvhNode::Leaf { first, count, .. } => found.extend(
+                    self.order[first..first + count]
+                        .iter()
+                        .filter(|&&i| self.boxes[i].intersects(aabb)),
+                ),
+                BvhNode::Split { left, right, .. } => {
+                    stack.push(right);
+                    stack.push(left);
+                }
+            }
+        }
+
+        found
+    }
+
+    /// The point on the surface closest to `p`, with its distance and the index of the polygon
+    /// it lies on. Returns `None` if there are no polygons.
+    pub fn nearest_polygon(&self, p: Vector) -> Option<(Vector, Unit, usize)> {
+        let mut best: Option<(Vector, Unit, usize)> = None;
+        if self.nodes.is_empty() {
+            return best;
+        }
+
+        let mut stack = vec![0];
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if let Some((_, distance, _)) = best {
+                if node.aabb().distance(p) >= distance {
+                    continue;
+                }
+            }
+
+            match *node {
+                BvhNode::Leaf { first, count, .. } => {
+                    for &i in &self.order[first..first + count] {
+                        let closest = self.polygons[i].closest_point(p);
+                        let distance = (closest - p).length();
+                        let closer = match best {
+                            Some((_, best_distance, _)) => distance < best_distance,
+                            None => true,
+                        };
+                        if closer {
+                            best = Some((closest, distance, i));
+                        }
+                    }
+                }
+                BvhNode::Split { left, right, .. } => {
+                    // Search the closer child first, so the other can often be skipped
+                    let (left_box, right_box) = (self.nodes[left].aabb(), self.nodes[right].aabb());
+                    if left_box.distance(p) <= right_box.distance(p) {
+                        stack.push(right);
+                        stack.push(left);
+                    } else {
+                        stack.push(left);
+                        stack.push(right);
+                    }
+                }
+            }
+        }
+
+        best
+    }
+}
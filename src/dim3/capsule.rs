@@ -0,0 +1,75 @@
+use dim3::Csg;
+use dim3::{Polygon, Vector, Vertex};
+use {Unit, EPSILON, UNIT_PI};
+
+impl Csg {
+    /// Cylinder between `a` and `b` with a half sphere on each end. Empty if `a` and `b` are the
+    /// same point, the radius is not positive, there are fewer than three slices or no stacks.
+    ///
+    /// * `slices` - Number of segments around the axis
+    /// * `stacks` - Number of segments from the cylinder edge to the tip of each half sphere
+    pub fn capsule(a: Vector, b: Vector, radius: Unit, slices: usize, stacks: usize) -> Csg {
+        if (b - a).length() <= EPSILON || radius <= 0. || slices < 3 || stacks == 0 {
+            return Csg::new();
+        }
+
+        let axis_z = (b - a).normalize();
+        let axis_x = axis_z.make_orthogonal().normalize();
+        let axis_y = axis_z.cross(axis_x);
+
+        // Rings from the tip at `a` to the tip at `b`, as (center, latitude). The tips are rings
+        // with radius zero.
+        let mut rings: Vec<(Vector, Unit)> = Vec::with_capacity(2 * stacks + 2);
+        for k in 0..(stacks + 1) {
+            let phi = UNIT_PI * 0.5 * ((k as Unit) / (stacks as Unit) - 1.);
+            rings.push((a, phi));
+        }
+        for k in 0..(stacks + 1) {
+            let phi = UNIT_PI * 0.5 * (k as Unit) / (stacks as Unit);
+            rings.push((b, phi));
+        }
+
+        let make_vertex = |ring: usize, slice: usize| -> Vertex {
+            let (center, phi) = rings[ring];
+            let (cos_phi, sin_phi) = if ring == 0 {
+                (0., -1.)
+            } else if ring == rings.len() - 1 {
+                (0., 1.)
+            } else {
+                (phi.cos(), phi.sin())
+            };
+
+            let theta = UNIT_PI * 2. * ((slice % slices) as Unit) / (slices as Unit);
+            let out = axis_x * theta.cos() + axis_y * theta.sin();
+            let normal = out * cos_phi + axis_z * sin_phi;
+
+            Vertex::new(center + normal * radius, normal)
+        };
+
+        let mut polys: Vec<Polygon> = Vec::new();
+
+        for k in 0..(rings.len() - 1) {
+            for i in 0..slices {
+                let mut verts = Vec::with_capacity(4);
+
+                if k == 0 {
+                    verts.push(make_vertex(k, i));
+                } else {
+                    verts.push(make_vertex(k, i));
+                    verts.push(make_vertex(k, i + 1));
+                }
+
+                if k + 1 == rings.len() - 1 {
+                    verts.push(make_vertex(k + 1, i));
+                } else {
+                    verts.push(make_vertex(k + 1, i + 1));
+                    verts.push(make_vertex(k + 1, i));
+                }
+
+                polys.push(Polygon::new(verts));
+            }
+        }
+
+        Csg::from_polygons(polys)
+    }
+}
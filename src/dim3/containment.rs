@@ -0,0 +1,71 @@
+use dim3::{BspNode, Csg, Polygon, Vector};
+use EPSILON;
+
+/// Where a point is relative to a solid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Containment {
+    Inside,
+    Outside,
+    /// Within `EPSILON` of the surface.
+    OnSurface,
+}
+
+/// True if `p`, which lies in the plane of `poly`, is inside the polygon or on its border.
+pub(crate) fn polygon_contains(poly: &Polygon, p: Vector) -> bool {
+    let len = poly.vertices.len();
+    (0..len).all(|i| {
+        let a = poly.vertices[i].position;
+        let b = poly.vertices[(i + 1) % len].position;
+        let edge = b - a;
+        // Distance from the edge line, positive on the inner side
+        edge.cross(p - a).dot(poly.plane.0) >= -EPSILON * edge.length()
+    })
+}
+
+impl BspNode {
+    /// Classify `p` against the solid this tree was built from. Space behind the leaves of the
+    /// tree is solid and space in front of them is empty.
+    ///
+    /// Build the tree once with `BspNode::new` to answer many queries on the same solid.
+    pub fn classify_point(&self, p: Vector) -> Containment {
+        let plane = match self.plane {
+            Some(ref plane) => plane,
+            None => return Containment::Outside,
+        };
+
+        let side = |node: &Option<Box<BspNode>>, solid: Containment| match node {
+            Some(node) => node.classify_point(p),
+            None => solid,
+        };
+
+        let t = plane.0.dot(p) - plane.1;
+        if t > EPSILON {
+            side(&self.front, Containment::Outside)
+        } else if t < -EPSILON {
+            side(&self.back, Containment::Inside)
+        } else if self.polygons.iter().any(|poly| polygon_contains(poly, p)) {
+            Containment::OnSurface
+        } else {
+            // On the plane but beside its polygons, both sides have to agree
+            let front = side(&self.front, Containment::Outside);
+            let back = side(&self.back, Containment::Inside);
+            if front == back {
+                front
+            } else {
+                Containment::OnSurface
+            }
+        }
+    }
+}
+
+impl Csg {
+    /// Find out if `p` is inside, outside or on the surface of the solid. This builds a BSP tree
+    /// on every call, see `BspNode::classify_point` for answering many queries.
+    pub fn contains_point(&self, p: Vector) -> Containment {
+        if !self.bounding_box().expand(EPSILON).contains(p) {
+            return Containment::Outside;
+        }
+
+        BspNode::new(Some(self.polygons.clone())).classify_point(p)
+    }
+}
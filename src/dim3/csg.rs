@@ -1,4 +1,4 @@
-use dim3::{BspNode, Polygon, Triangle, Vector, Vertex, Plane};
+use dim3::{BspNode, Matrix4, Polygon, Triangle, Vector, Vertex, Plane};
 use Unit;
 
 #[derive(Clone)]
@@ -151,18 +151,27 @@ impl Csg {
         })
     }
 
+    /// Scale along each axis. Normals are kept perpendicular to the surface, and a negative
+    /// factor mirrors the solid without turning it inside out. A zero factor flattens the solid
+    /// into an empty `Csg`.
     pub fn scale(self, v: Vector) -> Csg {
-        self.transform_vertices(|vert| Vertex {
-            position: Vector(
-                vert.position.0 * v.0,
-                vert.position.1 * v.1,
-                vert.position.2 * v.2,
-            ),
-            ..vert
-        })
+        self.transform(&Matrix4::scaling(v))
+    }
+
+    /// Mirror the solid in the plane through origo with normal `plane_normal`. Polygons are
+    /// turned around, so the mirrored solid can be used in boolean operations.
+    pub fn mirror(self, plane_normal: Vector) -> Csg {
+        self.transform(&Matrix4::mirror(plane_normal))
     }
 
     pub fn union(a: &Csg, b: &Csg) -> Csg {
+        // Solids with disjoint bounding boxes can not overlap, so the BSP trees are not needed
+        if !a.bounding_box().intersects(&b.bounding_box()) {
+            let mut polygons = a.polygons.clone();
+            polygons.extend(b.polygons.iter().cloned());
+            return Csg::from_polygons(polygons);
+        }
+
         let mut a = BspNode::new(Some(a.polygons.clone()));
         let mut b = BspNode::new(Some(b.polygons.clone()));
 
@@ -177,6 +186,10 @@ impl Csg {
     }
 
     pub fn subtract(a: &Csg, b: &Csg) -> Csg {
+        if !a.bounding_box().intersects(&b.bounding_box()) {
+            return a.clone();
+        }
+
         let mut a = BspNode::new(Some(a.polygons.clone()));
         let mut b = BspNode::new(Some(b.polygons.clone()));
 
@@ -193,6 +206,10 @@ impl Csg {
     }
 
     pub fn intersect(a: &Csg, b: &Csg) -> Csg {
+        if !a.bounding_box().intersects(&b.bounding_box()) {
+            return Csg::new();
+        }
+
         let mut a = BspNode::new(Some(a.polygons.clone()));
         let mut b = BspNode::new(Some(b.polygons.clone()));
 
@@ -1,6 +1,6 @@
 use dim3::Csg;
 use dim3::{Polygon, Vector, Vertex};
-use Unit;
+use {Unit, UNIT_PI};
 
 impl Csg {
     /// * `dim` - Dimensions of cube
@@ -40,4 +40,122 @@ impl Csg {
             .collect(),
         )
     }
+
+    /// Cube with edges and corners rounded off by `radius`. The radius is limited to half of the
+    /// smallest dimension.
+    ///
+    /// * `dim` - Dimensions of cube
+    /// * `segments` - Number of segments along each quarter circle of a rounding
+    pub fn rounded_cube(dim: Vector, radius: Unit, segments: usize, center: bool) -> Csg {
+        let radius = radius.min(dim.0 * 0.5).min(dim.1 * 0.5).min(dim.2 * 0.5);
+        if radius <= 0. || segments == 0 {
+            return Csg::cube(dim, center);
+        }
+
+        // (cos, sin) of `i / segments` of a quarter circle, exact at both ends
+        let quarter = |i: usize| -> (Unit, Unit) {
+            if i == 0 {
+                (1., 0.)
+            } else if i == segments {
+                (0., 1.)
+            } else {
+                let angle = UNIT_PI * 0.5 * (i as Unit) / (segments as Unit);
+                (angle.cos(), angle.sin())
+            }
+        };
+
+        // A sphere split into octants, where each octant is pushed out to a corner of the cube.
+        // Every quarter circle is sampled including both ends, so the seams between octants
+        // become the flat sides and the rounded edges.
+        let inner = Vector(dim.0 * 0.5, dim.1 * 0.5, dim.2 * 0.5) - Vector(radius, radius, radius);
+
+        // Around the Z axis, as (cos, sin, x sign, y sign)
+        let mut columns: Vec<(Unit, Unit, Unit, Unit)> = Vec::new();
+        for &(sx, sy, rotate) in [(1., 1., 0), (-1., 1., 1), (-1., -1., 2), (1., -1., 3)].iter() {
+            for i in 0..(segments + 1) {
+                let (c, s) = quarter(i);
+                let (c, s) = match rotate {
+                    0 => (c, s),
+                    1 => (-s, c),
+                    2 => (-c, -s),
+                    _ => (s, -c),
+                };
+                columns.push((c, s, sx, sy));
+            }
+        }
+
+        // From bottom to top, as (cos, sin, z sign)
+        let mut rows: Vec<(Unit, Unit, Unit)> = Vec::new();
+        for i in 0..(segments + 1) {
+            let (c, s) = quarter(segments - i);
+            rows.push((c, -s, -1.));
+        }
+        for i in 0..(segments + 1) {
+            let (c, s) = quarter(i);
+            rows.push((c, s, 1.));
+        }
+
+        let offset = if center {
+            Vector(0., 0., 0.)
+        } else {
+            Vector(dim.0 * 0.5, dim.1 * 0.5, dim.2 * 0.5)
+        };
+
+        let make_vertex = |row: usize, col: usize| -> Vertex {
+            let (rc, rs, sz) = rows[row];
+            let (cc, cs, sx, sy) = columns[col % columns.len()];
+            let normal = Vector(rc * cc, rc * cs, rs);
+            let corner = Vector(sx * inner.0, sy * inner.1, sz * inner.2);
+
+            Vertex::new(offset + corner + normal * radius, normal)
+        };
+
+        let mut polys: Vec<Polygon> = Vec::new();
+
+        for row in 0..(rows.len() - 1) {
+            for col in 0..columns.len() {
+                push_collapsed(
+                    &mut polys,
+                    vec![
+                        make_vertex(row, col),
+                        make_vertex(row, col + 1),
+                        make_vertex(row + 1, col + 1),
+                        make_vertex(row + 1, col),
+                    ],
+                );
+            }
+        }
+
+        // Flat bottom and top, spanned by the four distinct pole positions
+        let corners = |row: usize| -> Vec<Vertex> {
+            (0..4)
+                .map(|q| make_vertex(row, q * (segments + 1)))
+                .collect()
+        };
+
+        let mut bottom = corners(0);
+        bottom.reverse();
+        push_collapsed(&mut polys, bottom);
+        push_collapsed(&mut polys, corners(rows.len() - 1));
+
+        Csg::from_polygons(polys)
+    }
+}
+
+/// Remove repeated corners, which show up where the cube has no flat side between two roundings
+/// and at the poles. Skip the polygon when nothing is left of it.
+fn push_collapsed(polys: &mut Vec<Polygon>, mut verts: Vec<Vertex>) {
+    fn same_position(a: &Vertex, b: &Vertex) -> bool {
+        let (a, b) = (a.position, b.position);
+        a.0 == b.0 && a.1 == b.1 && a.2 == b.2
+    }
+
+    verts.dedup_by(|a, b| same_position(a, b));
+    if verts.len() > 1 && same_position(&verts[0], &verts[verts.len() - 1]) {
+        verts.pop();
+    }
+
+    if verts.len() >= 3 {
+        polys.push(Polygon::new(verts));
+    }
 }
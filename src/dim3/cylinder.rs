@@ -0,0 +1,99 @@
+use dim3::Csg;
+use dim3::{Polygon, Vector, Vertex};
+use {Unit, EPSILON, UNIT_PI};
+
+impl Csg {
+    /// Cylinder between `start` and `end` with both ends capped. Empty if `start` and `end` are
+    /// the same point, the radius is not positive or there are fewer than three slices.
+    ///
+    /// * `slices` - Number of sides around the axis
+    pub fn cylinder(start: Vector, end: Vector, radius: Unit, slices: usize) -> Csg {
+        Csg::cone(start, end, radius, radius, slices)
+    }
+
+    /// Cone frustum between `start` and `end`, with radius `r_start` at `start` and `r_end` at
+    /// `end`. Either radius may be zero to get a pointy cone, ends with a radius are capped.
+    /// Empty if `start` and `end` are the same point, neither radius is positive or there are
+    /// fewer than three slices.
+    ///
+    /// * `slices` - Number of sides around the axis
+    pub fn cone(start: Vector, end: Vector, r_start: Unit, r_end: Unit, slices: usize) -> Csg {
+        let ray = end - start;
+        let height = ray.length();
+        if height <= EPSILON
+            || r_start < 0.
+            || r_end < 0.
+            || (r_start <= 0. && r_end <= 0.)
+            || slices < 3
+        {
+            return Csg::new();
+        }
+
+        let axis_z = ray.normalize();
+        let is_y = axis_z.1.abs() > 0.5;
+        let axis_x = Vector(if is_y { 1. } else { 0. }, if is_y { 0. } else { 1. }, 0.)
+            .cross(axis_z)
+            .normalize();
+        let axis_y = axis_x.cross(axis_z).normalize();
+
+        // Side normals lean towards the narrow end of the cone
+        let slope = (r_start - r_end) / height;
+
+        let start_vert = Vertex::new(start, axis_z.negate());
+        let end_vert = Vertex::new(end, axis_z);
+
+        let point = |stack: Unit, slice: Unit, cap: Option<Vector>| -> Vertex {
+            let angle = slice * UNIT_PI * 2.;
+            let out = axis_x * angle.cos() + axis_y * angle.sin();
+            let radius = r_start + (r_end - r_start) * stack;
+            let position = start + ray * stack + out * radius;
+            let normal = match cap {
+                Some(normal) => normal,
+                None => (out + axis_z * slope).normalize(),
+            };
+
+            Vertex::new(position, normal)
+        };
+
+        let mut polys: Vec<Polygon> = Vec::new();
+
+        for i in 0..slices {
+            let t0 = (i as Unit) / (slices as Unit);
+            let t1 = ((i + 1) as Unit) / (slices as Unit);
+
+            if r_start > 0. {
+                polys.push(Polygon::new(vec![
+                    start_vert,
+                    point(0., t0, Some(start_vert.normal)),
+                    point(0., t1, Some(start_vert.normal)),
+                ]));
+            }
+
+            // Sides, a triangle when one of the ends comes to a point
+            let mut side = Vec::with_capacity(4);
+            if r_start > 0. {
+                side.push(point(0., t1, None));
+                side.push(point(0., t0, None));
+            } else {
+                side.push(point(0., (t0 + t1) * 0.5, None));
+            }
+            if r_end > 0. {
+                side.push(point(1., t0, None));
+                side.push(point(1., t1, None));
+            } else {
+                side.push(point(1., (t0 + t1) * 0.5, None));
+            }
+            polys.push(Polygon::new(side));
+
+            if r_end > 0. {
+                polys.push(Polygon::new(vec![
+                    end_vert,
+                    point(1., t1, Some(end_vert.normal)),
+                    point(1., t0, Some(end_vert.normal)),
+                ]));
+            }
+        }
+
+        Csg::from_polygons(polys)
+    }
+}
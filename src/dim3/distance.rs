@@ -0,0 +1,35 @@
+use dim3::{BspNode, Bvh, Containment, Csg, Vector};
+use Unit;
+
+/// Signed distance from the nearest surface point and the containment of `p`.
+fn signed(nearest: Option<(Vector, Unit, usize)>, containment: Containment) -> Unit {
+    match (nearest, containment) {
+        (None, _) => Unit::MAX,
+        (Some((_, distance, _)), Containment::Inside) => -distance,
+        (Some((_, distance, _)), _) => distance,
+    }
+}
+
+impl Csg {
+    /// The point on the surface closest to `p`, with its distance and the index of the polygon
+    /// it lies on. Returns `None` for a `Csg` without polygons.
+    ///
+    /// This builds a `Bvh` on every call, use `Bvh::nearest_polygon` for many queries.
+    pub fn closest_point(&self, p: Vector) -> Option<(Vector, Unit, usize)> {
+        Bvh::new(self).nearest_polygon(p)
+    }
+
+    /// Distance from `p` to the surface, negative inside of the solid. A `Csg` without polygons
+    /// is infinitely far away, giving `Unit::MAX`.
+    ///
+    /// For many queries, build a `Bvh` and a `BspNode` once and use `signed_distance_with`.
+    pub fn signed_distance(&self, p: Vector) -> Unit {
+        let bvh = Bvh::new(self);
+        signed(bvh.nearest_polygon(p), self.contains_point(p))
+    }
+
+    /// Same as `signed_distance`, using a prebuilt `Bvh` and `BspNode` of the same solid.
+    pub fn signed_distance_with(bvh: &Bvh, bsp: &BspNode, p: Vector) -> Unit {
+        signed(bvh.nearest_polygon(p), bsp.classify_point(p))
+    }
+}
@@ -0,0 +1,253 @@
+use dim2;
+use dim2::Point;
+use dim3::{Csg, Plane, Polygon, Vector, Vertex};
+use std::error::Error;
+use std::fmt;
+use {Unit, EPSILON, UNIT_PI};
+
+/// Reasons a 2D shape can't be turned into a solid.
+#[derive(Debug, PartialEq)]
+pub enum ExtrudeError {
+    /// The profile of a rotate extrusion has points on both sides of the axis.
+    CrossesAxis,
+    /// The path of a sweep has fewer than two distinct points.
+    PathTooShort,
+    /// A loft needs at least two sections.
+    TooFewSections,
+    /// The section with the given index is not a single closed outline without holes.
+    InvalidSection(usize),
+}
+
+impl fmt::Display for ExtrudeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExtrudeError::CrossesAxis => write!(f, "profile crosses the axis of revolution"),
+            ExtrudeError::PathTooShort => write!(f, "sweep path has fewer than two points"),
+            ExtrudeError::TooFewSections => write!(f, "loft needs at least two sections"),
+            ExtrudeError::InvalidSection(i) => {
+                write!(f, "loft section {} is not a single closed outline", i)
+            }
+        }
+    }
+}
+
+impl Error for ExtrudeError {}
+
+/// Add a polygon with flat normals from `positions`. Repeated corners are removed, and when the
+/// corners are not coplanar (twisted walls) the polygon is split into a fan of triangles.
+pub(crate) fn push_flat(polys: &mut Vec<Polygon>, mut positions: Vec<Vector>) {
+    fn same(a: &Vector, b: &Vector) -> bool {
+        (*a - *b).length() <= EPSILON
+    }
+
+    positions.dedup_by(|a, b| same(a, b));
+    while positions.len() > 1 && same(&positions[0], &positions[positions.len() - 1]) {
+        positions.pop();
+    }
+
+    if positions.len() < 3 {
+        return;
+    }
+
+    let flat = |corners: &[Vector]| -> Option<Polygon> {
+        // The first corner that is not collinear with its neighbours gives the plane
+        let plane = (0..corners.len())
+            .filter_map(|i| {
+                let len = corners.len();
+                Plane::try_from_points(corners[i], corners[(i + 1) % len], corners[(i + 2) % len])
+            })
+            .next()?;
+
+        if corners
+            .iter()
+            .any(|c| (plane.0.dot(*c) - plane.1).abs() > EPSILON * 10.)
+        {
+            return None;
+        }
+
+        Some(Polygon {
+            vertices: corners.iter().map(|c| Vertex::new(*c, plane.0)).collect(),
+            plane,
+        })
+    };
+
+    match flat(&positions) {
+        Some(poly) => polys.push(poly),
+        None => {
+            for i in 1..(positions.len() - 1) {
+                if let Some(poly) = flat(&[positions[0], positions[i], positions[i + 1]]) {
+                    polys.push(poly);
+                }
+            }
+        }
+    }
+}
+
+impl Csg {
+    /// Extrude a 2D shape along the Z axis, like OpenSCAD's `linear_extrude()`. Shapes with holes
+    /// are supported, the direction of the lines in `shape` does not matter. A negative `height`
+    /// extrudes downwards.
+    ///
+    /// * `twist_deg` - Clockwise rotation (seen from above) of the top relative to the bottom
+    /// * `slices` - Number of layers the walls are divided into, useful with `twist_deg`
+    /// * `scale_top` - Scale of the top relative to the bottom, per axis
+    /// * `center` - Center the result along the Z axis, otherwise it starts at `z = 0`
+    pub fn linear_extrude(
+        shape: &dim2::Csg,
+        height: Unit,
+        twist_deg: Unit,
+        slices: usize,
+        scale_top: Point,
+        center: bool,
+    ) -> Csg {
+        let slices = slices.max(1);
+        let z0 = if center { -height * 0.5 } else { 0. };
+
+        let place = |p: Point, layer: usize| -> Vector {
+            let t = (layer as Unit) / (slices as Unit);
+            let sx = 1. + (scale_top.0 - 1.) * t;
+            let sy = 1. + (scale_top.1 - 1.) * t;
+            let angle = -UNIT_PI * twist_deg * t / 180.;
+            let (s, c) = (angle.sin(), angle.cos());
+            let (x, y) = (p.0 * sx, p.1 * sy);
+
+            Vector(c * x - s * y, s * x + c * y, z0 + height * t)
+        };
+
+        let mut polys: Vec<Polygon> = Vec::new();
+
+        for region in shape.to_regions() {
+            // Outer loops are counter clockwise and holes clockwise, so every wall faces out
+            for outline in Some(&region.outer).into_iter().chain(region.holes.iter()) {
+                let len = outline.len();
+                for layer in 0..slices {
+                    for i in 0..len {
+                        let (a, b) = (outline[i], outline[(i + 1) % len]);
+                        push_flat(
+                            &mut polys,
+                            vec![
+                                place(a, layer),
+                                place(b, layer),
+                                place(b, layer + 1),
+                                place(a, layer + 1),
+                            ],
+                        );
+                    }
+                }
+            }
+
+            let (points, triangles) = region.triangulate();
+            for tri in triangles {
+                push_flat(
+                    &mut polys,
+                    tri.iter().rev().map(|&i| place(points[i], 0)).collect(),
+                );
+                push_flat(
+                    &mut polys,
+                    tri.iter().map(|&i| place(points[i], slices)).collect(),
+                );
+            }
+        }
+
+        // Extruding downwards mirrors everything along Z, which turns the solid inside out
+        if height < 0. {
+            Csg::from_polygons(polys).inverse()
+        } else {
+            Csg::from_polygons(polys)
+        }
+    }
+
+    /// Revolve a 2D profile around its Y axis, like OpenSCAD's `rotate_extrude()`. The X
+    /// coordinate of the profile is the distance from the axis, and the Y axis of the profile
+    /// becomes the Z axis of the result. Profiles entirely on the negative X side are mirrored.
+    ///
+    /// * `angle_deg` - Degrees to revolve, counter clockwise seen from above. Ends are capped
+    ///   when this is less than a full turn
+    /// * `segments` - Number of steps around the axis for the whole `angle_deg`
+    pub fn rotate_extrude(
+        shape: &dim2::Csg,
+        angle_deg: Unit,
+        segments: usize,
+    ) -> Result<Csg, ExtrudeError> {
+        let mut regions = shape.to_regions();
+
+        let points = || regions.iter().flat_map(|r| r.outer.iter());
+        let has_positive = points().any(|p| p.0 > EPSILON);
+        let has_negative = points().any(|p| p.0 < -EPSILON);
+
+        if has_positive && has_negative {
+            return Err(ExtrudeError::CrossesAxis);
+        }
+
+        if has_negative {
+            for region in regions.iter_mut() {
+                for outline in Some(&mut region.outer)
+                    .into_iter()
+                    .chain(region.holes.iter_mut())
+                {
+                    for p in outline.iter_mut() {
+                        p.0 = -p.0;
+                    }
+                    outline.reverse();
+                }
+            }
+        }
+
+        let full_turn = angle_deg.abs() >= 360. - EPSILON;
+        let span = UNIT_PI * angle_deg.abs().min(360.) / 180.;
+        let start = if angle_deg < 0. { -span } else { 0. };
+        let segments = segments.max(if full_turn { 3 } else { 1 });
+
+        let place = |p: Point, step: usize| -> Vector {
+            let step = if full_turn { step % segments } else { step };
+            let angle = start + span * (step as Unit) / (segments as Unit);
+
+            Vector(p.0 * angle.cos(), p.0 * angle.sin(), p.1)
+        };
+
+        let mut polys: Vec<Polygon> = Vec::new();
+
+        if span <= 0. {
+            return Ok(Csg::from_polygons(polys));
+        }
+
+        for region in &regions {
+            for outline in Some(&region.outer).into_iter().chain(region.holes.iter()) {
+                let len = outline.len();
+                for step in 0..segments {
+                    for i in 0..len {
+                        let (a, b) = (outline[i], outline[(i + 1) % len]);
+                        push_flat(
+                            &mut polys,
+                            vec![
+                                place(a, step),
+                                place(a, step + 1),
+                                place(b, step + 1),
+                                place(b, step),
+                            ],
+                        );
+                    }
+                }
+            }
+
+            if !full_turn {
+                let (points, triangles) = region.triangulate();
+                for tri in triangles {
+                    push_flat(
+                        &mut polys,
+                        tri.iter().map(|&i| place(points[i], 0)).collect(),
+                    );
+                    push_flat(
+                        &mut polys,
+                        tri.iter()
+                            .rev()
+                            .map(|&i| place(points[i], segments))
+                            .collect(),
+                    );
+                }
+            }
+        }
+
+        Ok(Csg::from_polygons(polys))
+    }
+}
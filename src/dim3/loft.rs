@@ -0,0 +1,131 @@
+use dim2;
+use dim2::{Point, Region};
+use dim3::extrude::push_flat;
+use dim3::{Csg, ExtrudeError, Placement, Polygon, Vector};
+use Unit;
+
+/// Add corners along the edges of `outline` until it has `count` corners. Original corners are
+/// kept, new ones go where the edges are the longest.
+fn resample(outline: &[Point], count: usize) -> Vec<Point> {
+    let len = outline.len();
+    let lengths: Vec<Unit> = (0..len)
+        .map(|i| (outline[(i + 1) % len] - outline[i]).length())
+        .collect();
+    let mut splits = vec![0usize; len];
+
+    for _ in len..count {
+        let longest = (0..len)
+            .max_by(|&a, &b| {
+                let la = lengths[a] / ((splits[a] + 1) as Unit);
+                let lb = lengths[b] / ((splits[b] + 1) as Unit);
+                la.partial_cmp(&lb).unwrap()
+            })
+            .unwrap();
+        splits[longest] += 1;
+    }
+
+    let mut points = Vec::with_capacity(count);
+    for i in 0..len {
+        let (a, b) = (outline[i], outline[(i + 1) % len]);
+        for k in 0..(splits[i] + 1) {
+            points.push(a.interpolate(&b, (k as Unit) / ((splits[i] + 1) as Unit)));
+        }
+    }
+
+    points
+}
+
+/// How far to rotate the corners of `next` to line them up with `prev` as closely as possible.
+fn best_offset(prev: &[Vector], next: &[Vector]) -> usize {
+    let len = prev.len();
+    (0..len)
+        .map(|offset| {
+            let cost: Unit = (0..len)
+                .map(|i| {
+                    let d = prev[i] - next[(i + offset) % len];
+                    d.dot(d)
+                })
+                .sum();
+            (offset, cost)
+        })
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .map_or(0, |(offset, _)| offset)
+}
+
+impl Csg {
+    /// Stitch a series of 2D outlines, each placed in 3D space, into one solid. Each section
+    /// must be a single outline without holes. Outlines are resampled to the same number of
+    /// corners and lined up, so different shapes (like a rectangle and a circle) can be mixed.
+    /// The first and the last section are capped.
+    pub fn loft(sections: &[(dim2::Csg, Placement)]) -> Result<Csg, ExtrudeError> {
+        if sections.len() < 2 {
+            return Err(ExtrudeError::TooFewSections);
+        }
+
+        let mut outlines: Vec<Vec<Point>> = Vec::with_capacity(sections.len());
+        for (i, (shape, _)) in sections.iter().enumerate() {
+            let mut regions = shape.to_regions();
+            if regions.len() != 1 || !regions[0].holes.is_empty() {
+                return Err(ExtrudeError::InvalidSection(i));
+            }
+            outlines.push(regions.remove(0).outer);
+        }
+
+        let count = outlines.iter().map(|o| o.len()).max().unwrap();
+        let outlines: Vec<Vec<Point>> = outlines.iter().map(|o| resample(o, count)).collect();
+
+        let mut rings: Vec<Vec<Vector>> = Vec::with_capacity(sections.len());
+        let mut aligned: Vec<Vec<Point>> = Vec::with_capacity(sections.len());
+        for (outline, (_, placement)) in outlines.iter().zip(sections.iter()) {
+            let mut ring: Vec<Vector> = outline.iter().map(|p| placement.place(*p)).collect();
+            let offset = rings.last().map_or(0, |prev| best_offset(prev, &ring));
+            ring.rotate_left(offset);
+
+            let mut outline = outline.clone();
+            outline.rotate_left(offset);
+            rings.push(ring);
+            aligned.push(outline);
+        }
+
+        let mut polys: Vec<Polygon> = Vec::new();
+
+        for k in 0..(rings.len() - 1) {
+            let (lower, upper) = (&rings[k], &rings[k + 1]);
+            for i in 0..count {
+                let j = (i + 1) % count;
+                push_flat(&mut polys, vec![lower[i], lower[j], upper[j], upper[i]]);
+            }
+        }
+
+        // Without holes, the triangles index straight into the rings
+        let cap_triangles = |outline: &Vec<Point>| {
+            Region {
+                outer: outline.clone(),
+                holes: Vec::new(),
+            }
+            .triangulate()
+            .1
+        };
+
+        for tri in cap_triangles(&aligned[0]) {
+            push_flat(&mut polys, tri.iter().rev().map(|&i| rings[0][i]).collect());
+        }
+
+        let last = rings.len() - 1;
+        for tri in cap_triangles(&aligned[last]) {
+            push_flat(&mut polys, tri.iter().map(|&i| rings[last][i]).collect());
+        }
+
+        let mut csg = Csg::from_polygons(polys);
+
+        // Outlines are counter clockwise, which faces outwards only when the sections are
+        // stacked along the normal of their placements
+        let first = &sections[0].1;
+        let travel = sections[sections.len() - 1].1.origin - first.origin;
+        if travel.dot(first.normal()) < 0. {
+            csg = csg.inverse();
+        }
+
+        Ok(csg)
+    }
+}
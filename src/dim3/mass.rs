@@ -0,0 +1,118 @@
+use dim3::{Csg, Vector};
+use Unit;
+
+/// Volume, first and second moments of a solid, relative to a reference point.
+struct Moments {
+    volume: Unit,
+    /// Integral of the position over the volume.
+    first: Vector,
+    /// Integral of `x_i * x_j` over the volume.
+    second: [[Unit; 3]; 3],
+}
+
+impl Csg {
+    /// Split each polygon into a fan of triangles and call `func` with the corners of each,
+    /// relative to `origin`.
+    fn for_each_fan_triangle<F>(&self, origin: Vector, mut func: F)
+    where
+        F: FnMut(Vector, Vector, Vector),
+    {
+        for poly in &self.polygons {
+            let a = poly.vertices[0].position - origin;
+            for pair in poly.vertices[1..].windows(2) {
+                func(a, pair[0].position - origin, pair[1].position - origin);
+            }
+        }
+    }
+
+    /// Sum the moments of the tetrahedra between `origin` and every surface triangle. By the
+    /// divergence theorem, the parts outside of a closed surface cancel out.
+    fn moments(&self, origin: Vector) -> Moments {
+        let mut m = Moments {
+            volume: 0.,
+            first: Vector(0., 0., 0.),
+            second: [[0.; 3]; 3],
+        };
+
+        self.for_each_fan_triangle(origin, |a, b, c| {
+            let det = a.dot(b.cross(c));
+            m.volume += det / 6.;
+            m.first = m.first + (a + b + c) * (det / 24.);
+
+            let corners = [[a.0, a.1, a.2], [b.0, b.1, b.2], [c.0, c.1, c.2]];
+            for i in 0..3 {
+                for j in 0..3 {
+                    let sum_i: Unit = corners.iter().map(|v| v[i]).sum();
+                    let sum_j: Unit = corners.iter().map(|v| v[j]).sum();
+                    let products: Unit = corners.iter().map(|v| v[i] * v[j]).sum();
+                    m.second[i][j] += det / 120. * (products + sum_i * sum_j);
+                }
+            }
+        });
+
+        m
+    }
+
+    /// Somewhere on the surface, used as reference point to keep the sums small.
+    fn reference_point(&self) -> Vector {
+        self.polygons
+            .first()
+            .map_or(Vector(0., 0., 0.), |p| p.vertices[0].position)
+    }
+
+    /// Enclosed volume. The surface is expected to be closed and facing outwards.
+    pub fn volume(&self) -> Unit {
+        self.moments(self.reference_point()).volume
+    }
+
+    pub fn surface_area(&self) -> Unit {
+        let mut area = 0.;
+        self.for_each_fan_triangle(Vector(0., 0., 0.), |a, b, c| {
+            area += (b - a).cross(c - a).length() * 0.5;
+        });
+        area
+    }
+
+    /// Center of the volume, which is the center of mass for a uniform density. Solids without
+    /// volume give origo.
+    pub fn centroid(&self) -> Vector {
+        let origin = self.reference_point();
+        let m = self.moments(origin);
+
+        if m.volume == 0. {
+            Vector(0., 0., 0.)
+        } else {
+            origin + m.first / m.volume
+        }
+    }
+
+    /// Inertia tensor around the centroid, for a uniform `density`. Row and column `i` belong to
+    /// axis `i`, so `[0][0]` is the moment of inertia around the X axis.
+    pub fn inertia_tensor(&self, density: Unit) -> [[Unit; 3]; 3] {
+        let origin = self.reference_point();
+        let m = self.moments(origin);
+        if m.volume == 0. {
+            return [[0.; 3]; 3];
+        }
+
+        // Move the second moments from the reference point to the centroid
+        let c = m.first / m.volume;
+        let c = [c.0, c.1, c.2];
+        let mut covariance = [[0.; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                covariance[i][j] = density * (m.second[i][j] - m.volume * c[i] * c[j]);
+            }
+        }
+
+        let trace = covariance[0][0] + covariance[1][1] + covariance[2][2];
+        let mut tensor = [[0.; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                let diagonal = if i == j { trace } else { 0. };
+                tensor[i][j] = diagonal - covariance[i][j];
+            }
+        }
+        tensor
+    }
+}
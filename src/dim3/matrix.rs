@@ -0,0 +1,282 @@
+use dim3::{Csg, Plane, Vector};
+use std::ops::Mul;
+use {Unit, EPSILON, UNIT_PI};
+
+/// Affine transformation as a 4x4 matrix, stored row by row. Vectors are columns, so the product
+/// `a * b` applies `b` first and then `a`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Matrix4(pub [[Unit; 4]; 4]);
+
+impl Matrix4 {
+    pub fn identity() -> Matrix4 {
+        Matrix4::from_axes(
+            Vector(1., 0., 0.),
+            Vector(0., 1., 0.),
+            Vector(0., 0., 1.),
+            Vector(0., 0., 0.),
+        )
+    }
+
+    /// Matrix mapping the X, Y and Z axes to `x`, `y` and `z`, followed by a translation.
+    pub fn from_axes(x: Vector, y: Vector, z: Vector, translation: Vector) -> Matrix4 {
+        Matrix4([
+            [x.0, y.0, z.0, translation.0],
+            [x.1, y.1, z.1, translation.1],
+            [x.2, y.2, z.2, translation.2],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    pub fn translation(v: Vector) -> Matrix4 {
+        let mut m = Matrix4::identity();
+        m.0[0][3] = v.0;
+        m.0[1][3] = v.1;
+        m.0[2][3] = v.2;
+        m
+    }
+
+    pub fn scaling(v: Vector) -> Matrix4 {
+        Matrix4::from_axes(
+            Vector(v.0, 0., 0.),
+            Vector(0., v.1, 0.),
+            Vector(0., 0., v.2),
+            Vector(0., 0., 0.),
+        )
+    }
+
+    /// Rotation around `axis`, clockwise when the axis points towards the viewer like
+    /// `Csg::rotate`. The axis does not have to be normalized.
+    pub fn rotation(axis: Vector, angle_deg: Unit) -> Matrix4 {
+        let Vector(x, y, z) = axis.normalize();
+        let (sin, cos) = (-angle_deg * UNIT_PI / 180.).sin_cos();
+        let t = 1. - cos;
+
+        Matrix4([
+            [
+                t * x * x + cos,
+                t * x * y - sin * z,
+                t * x * z + sin * y,
+                0.,
+            ],
+            [
+                t * x * y + sin * z,
+                t * y * y + cos,
+                t * y * z - sin * x,
+                0.,
+            ],
+            [
+                t * x * z - sin * y,
+                t * y * z + sin * x,
+                t * z * z + cos,
+                0.,
+            ],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    /// Rotate around the X axis, then the Y axis and last the Z axis.
+    pub fn euler(x_deg: Unit, y_deg: Unit, z_deg: Unit) -> Matrix4 {
+        Matrix4::rotation(Vector(0., 0., 1.), z_deg)
+            * Matrix4::rotation(Vector(0., 1., 0.), y_deg)
+            * Matrix4::rotation(Vector(1., 0., 0.), x_deg)
+    }
+
+    /// View matrix of a camera at `eye` looking at `target`, like `gluLookAt`. The camera looks
+    /// along its negative Z axis, with `up` pointing along its Y axis as closely as possible.
+    pub fn look_at(eye: Vector, target: Vector, up: Vector) -> Matrix4 {
+        let forward = (target - eye).normalize();
+        let side = forward.cross(up);
+        let side = if side.length() > EPSILON {
+            side.normalize()
+        } else {
+            forward.make_orthogonal().normalize()
+        };
+        let up = side.cross(forward);
+
+        Matrix4([
+            [side.0, side.1, side.2, -side.dot(eye)],
+            [up.0, up.1, up.2, -up.dot(eye)],
+            [-forward.0, -forward.1, -forward.2, forward.dot(eye)],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    /// Reflection in the plane through origo with normal `normal`.
+    pub fn mirror(normal: Vector) -> Matrix4 {
+        let n = normal.normalize();
+        let reflect = |axis: Vector| axis - n * (2. * n.dot(axis));
+
+        Matrix4::from_axes(
+            reflect(Vector(1., 0., 0.)),
+            reflect(Vector(0., 1., 0.)),
+            reflect(Vector(0., 0., 1.)),
+            Vector(0., 0., 0.),
+        )
+    }
+
+    /// Shear where each coordinate moves in proportion to the others, `xy` being how far X moves
+    /// per unit of Y and so on.
+    pub fn shear(xy: Unit, xz: Unit, yx: Unit, yz: Unit, zx: Unit, zy: Unit) -> Matrix4 {
+        Matrix4([
+            [1., xy, xz, 0.],
+            [yx, 1., yz, 0.],
+            [zx, zy, 1., 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    pub fn transpose(&self) -> Matrix4 {
+        let mut m = Matrix4([[0.; 4]; 4]);
+        for (r, row) in self.0.iter().enumerate() {
+            for (c, value) in row.iter().enumerate() {
+                m.0[c][r] = *value;
+            }
+        }
+        m
+    }
+
+    /// Sub-determinants shared by `determinant` and `inverse`.
+    fn minors(&self) -> ([Unit; 6], [Unit; 6]) {
+        let a = &self.0;
+        let s = [
+            a[0][0] * a[1][1] - a[1][0] * a[0][1],
+            a[0][0] * a[1][2] - a[1][0] * a[0][2],
+            a[0][0] * a[1][3] - a[1][0] * a[0][3],
+            a[0][1] * a[1][2] - a[1][1] * a[0][2],
+            a[0][1] * a[1][3] - a[1][1] * a[0][3],
+            a[0][2] * a[1][3] - a[1][2] * a[0][3],
+        ];
+        let c = [
+            a[2][0] * a[3][1] - a[3][0] * a[2][1],
+            a[2][0] * a[3][2] - a[3][0] * a[2][2],
+            a[2][0] * a[3][3] - a[3][0] * a[2][3],
+            a[2][1] * a[3][2] - a[3][1] * a[2][2],
+            a[2][1] * a[3][3] - a[3][1] * a[2][3],
+            a[2][2] * a[3][3] - a[3][2] * a[2][3],
+        ];
+        (s, c)
+    }
+
+    fn determinant_of_minors(s: &[Unit; 6], c: &[Unit; 6]) -> Unit {
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    pub fn determinant(&self) -> Unit {
+        let (s, c) = self.minors();
+        Matrix4::determinant_of_minors(&s, &c)
+    }
+
+    /// The inverse matrix, or `None` if the matrix flattens space and cannot be inverted. Only a
+    /// zero determinant counts as flat, so tiny scales are still inverted.
+    pub fn inverse(&self) -> Option<Matrix4> {
+        let (s, c) = self.minors();
+        let det = Matrix4::determinant_of_minors(&s, &c);
+        if det == 0. || !det.is_finite() {
+            return None;
+        }
+
+        let a = &self.0;
+        let inv = 1. / det;
+        Some(Matrix4([
+            [
+                (a[1][1] * c[5] - a[1][2] * c[4] + a[1][3] * c[3]) * inv,
+                (-a[0][1] * c[5] + a[0][2] * c[4] - a[0][3] * c[3]) * inv,
+                (a[3][1] * s[5] - a[3][2] * s[4] + a[3][3] * s[3]) * inv,
+                (-a[2][1] * s[5] + a[2][2] * s[4] - a[2][3] * s[3]) * inv,
+            ],
+            [
+                (-a[1][0] * c[5] + a[1][2] * c[2] - a[1][3] * c[1]) * inv,
+                (a[0][0] * c[5] - a[0][2] * c[2] + a[0][3] * c[1]) * inv,
+                (-a[3][0] * s[5] + a[3][2] * s[2] - a[3][3] * s[1]) * inv,
+                (a[2][0] * s[5] - a[2][2] * s[2] + a[2][3] * s[1]) * inv,
+            ],
+            [
+                (a[1][0] * c[4] - a[1][1] * c[2] + a[1][3] * c[0]) * inv,
+                (-a[0][0] * c[4] + a[0][1] * c[2] - a[0][3] * c[0]) * inv,
+                (a[3][0] * s[4] - a[3][1] * s[2] + a[3][3] * s[0]) * inv,
+                (-a[2][0] * s[4] + a[2][1] * s[2] - a[2][3] * s[0]) * inv,
+            ],
+            [
+                (-a[1][0] * c[3] + a[1][1] * c[1] - a[1][2] * c[0]) * inv,
+                (a[0][0] * c[3] - a[0][1] * c[1] + a[0][2] * c[0]) * inv,
+                (-a[3][0] * s[3] + a[3][1] * s[1] - a[3][2] * s[0]) * inv,
+                (a[2][0] * s[3] - a[2][1] * s[1] + a[2][2] * s[0]) * inv,
+            ],
+        ]))
+    }
+
+    /// Transform a position, including the translation.
+    pub fn transform_point(&self, v: Vector) -> Vector {
+        let a = &self.0;
+        let w = a[3][0] * v.0 + a[3][1] * v.1 + a[3][2] * v.2 + a[3][3];
+        Vector(
+            a[0][0] * v.0 + a[0][1] * v.1 + a[0][2] * v.2 + a[0][3],
+            a[1][0] * v.0 + a[1][1] * v.1 + a[1][2] * v.2 + a[1][3],
+            a[2][0] * v.0 + a[2][1] * v.1 + a[2][2] * v.2 + a[2][3],
+        ) / w
+    }
+
+    /// Transform a direction, ignoring the translation.
+    pub fn transform_vector(&self, v: Vector) -> Vector {
+        let a = &self.0;
+        Vector(
+            a[0][0] * v.0 + a[0][1] * v.1 + a[0][2] * v.2,
+            a[1][0] * v.0 + a[1][1] * v.1 + a[1][2] * v.2,
+            a[2][0] * v.0 + a[2][1] * v.1 + a[2][2] * v.2,
+        )
+    }
+}
+
+impl Mul for Matrix4 {
+    type Output = Matrix4;
+
+    fn mul(self, rhs: Matrix4) -> Matrix4 {
+        let mut m = Matrix4([[0.; 4]; 4]);
+        for r in 0..4 {
+            for c in 0..4 {
+                m.0[r][c] = (0..4).map(|i| self.0[r][i] * rhs.0[i][c]).sum();
+            }
+        }
+        m
+    }
+}
+
+impl Csg {
+    /// Apply an affine transformation. Normals are transformed with the inverse transpose, so
+    /// they stay perpendicular to the surface, and polygons are turned around when the matrix
+    /// mirrors, so the solid is not turned inside out.
+    ///
+    /// A matrix with a zero determinant flattens the solid, which gives an empty `Csg`.
+    pub fn transform(mut self, m: &Matrix4) -> Csg {
+        let normal_matrix = match m.inverse() {
+            Some(inverse) => inverse.transpose(),
+            None => return Csg::new(),
+        };
+        let mirrored = m.determinant() < 0.;
+
+        let transform_normal = |n: Vector| {
+            let n = normal_matrix.transform_vector(n);
+            let len = n.length();
+            if len > 0. {
+                n / len
+            } else {
+                n
+            }
+        };
+
+        for poly in &mut self.polygons {
+            for vert in &mut poly.vertices {
+                vert.position = m.transform_point(vert.position);
+                vert.normal = transform_normal(vert.normal);
+            }
+            if mirrored {
+                poly.vertices.reverse();
+            }
+
+            let normal = transform_normal(poly.plane.0);
+            poly.plane = Plane(normal, normal.dot(poly.vertices[0].position));
+        }
+
+        self
+    }
+}
@@ -0,0 +1,104 @@
+use dim3::weld::Welder;
+use dim3::{Csg, IVector, Vector};
+use std::collections::HashMap;
+use Unit;
+
+/// Flat vertices at the same position share a normal when the normals round to the same
+/// multiple of `1 / NORMAL_STEP`.
+const NORMAL_STEP: Unit = 1e4;
+
+/// How `Csg::to_indexed_mesh` assigns normals to vertices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeshNormals {
+    /// Faces only share vertices with coplanar neighbours, and use the normal of the face, so
+    /// edges look sharp.
+    Flat,
+    /// Faces share vertices, with normals averaged over the faces around them, weighted by area.
+    Smooth,
+}
+
+/// Triangle mesh with shared vertices, as used by GPU buffers and most mesh file formats.
+/// Triangles are counter clockwise when seen from the outside.
+#[derive(Clone, Debug)]
+pub struct IndexedMesh {
+    pub positions: Vec<Vector>,
+    pub normals: Vec<Vector>,
+    /// Indices into `positions` and `normals`, three for each triangle.
+    pub indices: Vec<[usize; 3]>,
+}
+
+impl Csg {
+    /// Convert to an indexed triangle mesh. Positions closer than `tolerance` to each other are
+    /// merged, and triangles that collapse when doing so are left out.
+    ///
+    /// Panics if `tolerance` is not positive.
+    pub fn to_indexed_mesh(&self, tolerance: Unit, normals: MeshNormals) -> IndexedMesh {
+        assert!(tolerance > 0., "Welding tolerance must be positive");
+
+        let mut welder = Welder::new(tolerance);
+        // Flat meshes have a vertex for each welded position and face direction, so coplanar
+        // polygons share the vertices along their common edges
+        let mut flat_vertices: HashMap<(usize, IVector), usize> = HashMap::new();
+        let mut mesh = IndexedMesh {
+            positions: Vec::new(),
+            normals: Vec::new(),
+            indices: Vec::new(),
+        };
+
+        for poly in &self.polygons {
+            let normal = poly.plane.0;
+            let corners: Vec<usize> = poly
+                .vertices
+                .iter()
+                .map(|v| {
+                    let position = welder.index(v.position);
+                    match normals {
+                        MeshNormals::Smooth => position,
+                        MeshNormals::Flat => {
+                            let key = (position, normal.discreet(NORMAL_STEP));
+                            let (mesh, welded) = (&mut mesh, welder.positions[position]);
+                            *flat_vertices.entry(key).or_insert_with(|| {
+                                mesh.positions.push(welded);
+                                mesh.normals.push(normal);
+                                mesh.positions.len() - 1
+                            })
+                        }
+                    }
+                })
+                .collect();
+
+            for pair in corners[1..].windows(2) {
+                let triangle = [corners[0], pair[0], pair[1]];
+                if triangle[0] != triangle[1]
+                    && triangle[1] != triangle[2]
+                    && triangle[2] != triangle[0]
+                {
+                    mesh.indices.push(triangle);
+                }
+            }
+        }
+
+        if normals == MeshNormals::Smooth {
+            mesh.positions = welder.positions;
+            mesh.normals = vec![Vector(0., 0., 0.); mesh.positions.len()];
+
+            // The cross product is twice the triangle area, giving bigger faces more weight
+            for &[a, b, c] in &mesh.indices {
+                let (pa, pb, pc) = (mesh.positions[a], mesh.positions[b], mesh.positions[c]);
+                let weighted = (pb - pa).cross(pc - pa);
+                for &i in &[a, b, c] {
+                    mesh.normals[i] = mesh.normals[i] + weighted;
+                }
+            }
+
+            for n in &mut mesh.normals {
+                let length = n.length();
+                if length > 0. {
+                    *n = *n / length;
+                }
+            }
+        }
+
+        mesh
+    }
+}
@@ -1,16 +1,53 @@
+mod aabb;
 mod bsp_node;
+mod bvh;
+mod capsule;
+mod containment;
 mod csg;
 mod cube;
+mod cylinder;
+mod distance;
+mod extrude;
+mod loft;
+mod mass;
+mod matrix;
+mod mesh;
+mod obj;
+mod overlap;
+mod placement;
 mod plane;
+mod platonic;
 mod polygon;
+mod polyhedron;
+mod projection;
+mod quaternion;
+mod raycast;
+mod section;
 mod sphere;
+mod stl;
+mod sweep;
+mod torus;
 mod vector;
 mod vertex;
+mod weld;
 
+pub use self::aabb::Aabb;
 pub use self::bsp_node::BspNode;
+pub use self::bvh::Bvh;
+pub use self::containment::Containment;
 pub use self::csg::Csg;
+pub use self::extrude::ExtrudeError;
+pub use self::matrix::Matrix4;
+pub use self::mesh::{IndexedMesh, MeshNormals};
+pub use self::obj::ObjError;
+pub use self::placement::Placement;
 pub use self::plane::Plane;
 pub use self::polygon::Polygon;
+pub use self::polyhedron::PolyhedronError;
+pub use self::quaternion::Quaternion;
+pub use self::raycast::Hit;
+pub use self::stl::StlError;
+pub use self::sweep::SweepFrame;
 pub use self::vector::{IVector, Vector};
 pub use self::vertex::Vertex;
 
@@ -0,0 +1,213 @@
+use dim3::weld::Welder;
+use dim3::{Csg, Polygon, Vector, Vertex};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use {Unit, EPSILON};
+
+/// Errors from reading a Wavefront OBJ file. Line numbers are 1-based.
+#[derive(Debug)]
+pub enum ObjError {
+    Io(io::Error),
+    /// Malformed statement, with the offending line number.
+    Parse(usize, String),
+    /// The face on the given line has fewer than three corners or collinear corners.
+    DegenerateFace(usize),
+}
+
+impl fmt::Display for ObjError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ObjError::Io(e) => write!(f, "OBJ I/O error: {}", e),
+            ObjError::Parse(line, msg) => write!(f, "OBJ parse error on line {}: {}", line, msg),
+            ObjError::DegenerateFace(line) => write!(f, "OBJ face on line {} is degenerate", line),
+        }
+    }
+}
+
+impl Error for ObjError {}
+
+impl From<io::Error> for ObjError {
+    fn from(e: io::Error) -> ObjError {
+        ObjError::Io(e)
+    }
+}
+
+/// Resolve a 1-based (or negative, relative to the end) OBJ index into `len` elements.
+fn resolve_index(token: &str, len: usize, line_nr: usize) -> Result<usize, ObjError> {
+    let index: i64 = token
+        .parse()
+        .map_err(|_| ObjError::Parse(line_nr, format!("invalid index '{}'", token)))?;
+
+    let resolved = if index < 0 {
+        len as i64 + index
+    } else {
+        index - 1
+    };
+
+    if resolved < 0 || resolved >= len as i64 {
+        Err(ObjError::Parse(
+            line_nr,
+            format!("index {} out of range", index),
+        ))
+    } else {
+        Ok(resolved as usize)
+    }
+}
+
+fn parse_vector<'a, I>(tokens: &mut I, line_nr: usize) -> Result<Vector, ObjError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let mut coord = || -> Result<Unit, ObjError> {
+        tokens
+            .next()
+            .and_then(|t| t.parse::<Unit>().ok())
+            .ok_or_else(|| ObjError::Parse(line_nr, "invalid coordinate".to_string()))
+    };
+
+    Ok(Vector(coord()?, coord()?, coord()?))
+}
+
+impl Csg {
+    /// Write all polygons as a Wavefront OBJ file. See `to_obj_groups`.
+    pub fn to_obj(&self, w: impl Write) -> io::Result<()> {
+        Csg::to_obj_groups(&[("", self)], w)
+    }
+
+    /// Write several named parts into one Wavefront OBJ file, each under a `g` statement (unless
+    /// the name is empty). Positions and normals are shared between all parts and written only
+    /// once. Polygons are written as they are, without triangulation.
+    pub fn to_obj_groups(groups: &[(&str, &Csg)], mut w: impl Write) -> io::Result<()> {
+        let mut positions = Welder::new(EPSILON);
+        let mut normals = Welder::new(EPSILON);
+
+        let faces: Vec<Vec<Vec<(usize, usize)>>> = groups
+            .iter()
+            .map(|(_, csg)| {
+                csg.polygons
+                    .iter()
+                    .map(|poly| {
+                        poly.vertices
+                            .iter()
+                            .map(|v| (positions.index(v.position), normals.index(v.normal)))
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        for p in &positions.positions {
+            writeln!(w, "v {} {} {}", p.0, p.1, p.2)?;
+        }
+
+        for n in &normals.positions {
+            writeln!(w, "vn {} {} {}", n.0, n.1, n.2)?;
+        }
+
+        for ((name, _), group_faces) in groups.iter().zip(faces.iter()) {
+            if !name.is_empty() {
+                writeln!(w, "g {}", name)?;
+            }
+
+            for face in group_faces {
+                write!(w, "f")?;
+                for (v, vn) in face {
+                    write!(w, " {}//{}", v + 1, vn + 1)?;
+                }
+                writeln!(w)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read a Wavefront OBJ file into one `Csg`, ignoring groups. See `from_obj_groups`.
+    pub fn from_obj(r: impl Read) -> Result<Csg, ObjError> {
+        let mut polygons = Vec::new();
+
+        for (_, mut csg) in Csg::from_obj_groups(r)? {
+            polygons.append(&mut csg.polygons);
+        }
+
+        Ok(Csg::from_polygons(polygons))
+    }
+
+    /// Read a Wavefront OBJ file, one `Csg` per `g` or `o` name in order of first appearance.
+    /// Faces before the first group end up under an empty name. Each `f` statement becomes one
+    /// polygon. Vertex normals are used when the face refers to them, otherwise the normal of
+    /// the face plane is used.
+    pub fn from_obj_groups(r: impl Read) -> Result<Vec<(String, Csg)>, ObjError> {
+        let mut positions: Vec<Vector> = Vec::new();
+        let mut normals: Vec<Vector> = Vec::new();
+        let mut groups: Vec<(String, Csg)> = Vec::new();
+        let mut current: Option<usize> = None;
+
+        for (i, line) in BufReader::new(r).lines().enumerate() {
+            let line = line?;
+            let line_nr = i + 1;
+            let mut tokens = line.split_whitespace();
+
+            match tokens.next() {
+                Some("v") => positions.push(parse_vector(&mut tokens, line_nr)?),
+                Some("vn") => normals.push(parse_vector(&mut tokens, line_nr)?),
+                Some("g") | Some("o") => {
+                    let name = tokens.collect::<Vec<&str>>().join(" ");
+                    current = Some(match groups.iter().position(|(n, _)| *n == name) {
+                        Some(index) => index,
+                        None => {
+                            groups.push((name, Csg::new()));
+                            groups.len() - 1
+                        }
+                    });
+                }
+                Some("f") => {
+                    let mut vertices = Vec::new();
+                    let mut has_normals = true;
+
+                    for corner in tokens {
+                        // v, v/vt, v//vn or v/vt/vn
+                        let mut refs = corner.split('/');
+                        let v = refs.next().unwrap_or("");
+                        let position = positions[resolve_index(v, positions.len(), line_nr)?];
+
+                        let normal = match refs.nth(1) {
+                            Some(vn) if !vn.is_empty() => {
+                                normals[resolve_index(vn, normals.len(), line_nr)?]
+                            }
+                            _ => {
+                                has_normals = false;
+                                Vector(0., 0., 0.)
+                            }
+                        };
+
+                        vertices.push(Vertex::new(position, normal));
+                    }
+
+                    let mut poly =
+                        Polygon::try_new(vertices).ok_or(ObjError::DegenerateFace(line_nr))?;
+
+                    if !has_normals {
+                        let normal = poly.plane.0;
+                        for v in poly.vertices.iter_mut() {
+                            v.normal = normal;
+                        }
+                    }
+
+                    let index = match current {
+                        Some(index) => index,
+                        None => {
+                            groups.push((String::new(), Csg::new()));
+                            groups.len() - 1
+                        }
+                    };
+                    current = Some(index);
+                    groups[index].1.polygons.push(poly);
+                }
+                _ => (),
+            }
+        }
+
+        Ok(groups)
+    }
+}
@@ -0,0 +1,220 @@
+use dim3::{Aabb, Bvh, Containment, Csg, Plane, Polygon, Vector};
+use {Unit, EPSILON};
+
+fn plane_distances(poly: &Polygon, plane: &Plane) -> Vec<Unit> {
+    poly.vertices
+        .iter()
+        .map(|v| plane.0.dot(v.position) - plane.1)
+        .collect()
+}
+
+/// Points where the polygon meets the plane, given the distances of its vertices to it.
+fn plane_crossing(poly: &Polygon, dists: &[Unit]) -> Vec<Vector> {
+    let len = poly.vertices.len();
+    let mut points = Vec::new();
+
+    for i in 0..len {
+        let j = (i + 1) % len;
+        let (di, dj) = (dists[i], dists[j]);
+        let (a, b) = (poly.vertices[i].position, poly.vertices[j].position);
+
+        if di.abs() <= EPSILON {
+            points.push(a);
+        } else if (di < -EPSILON && dj > EPSILON) || (di > EPSILON && dj < -EPSILON) {
+            points.push(a.lerp(b, di / (di - dj)));
+        }
+    }
+
+    points
+}
+
+/// Range covered by `points` along `axis`.
+fn project(points: &[Vector], axis: Vector) -> (Unit, Unit) {
+    points.iter().fold((Unit::MAX, Unit::MIN), |(min, max), p| {
+        let t = p.dot(axis);
+        (min.min(t), max.max(t))
+    })
+}
+
+/// Separating axis test for two polygons in the same plane.
+fn coplanar_overlap(p: &Polygon, q: &Polygon) -> bool {
+    let points =
+        |poly: &Polygon| -> Vec<Vector> { poly.vertices.iter().map(|v| v.position).collect() };
+    let (pp, qp) = (points(p), points(q));
+    let normal = p.plane.0;
+
+    for poly in &[&pp, &qp] {
+        let len = poly.len();
+        for i in 0..len {
+            let edge = poly[(i + 1) % len] - poly[i];
+            let axis = edge.cross(normal);
+            let length = axis.length();
+            if length <= EPSILON {
+                continue;
+            }
+
+            let axis = axis / length;
+            let (a, b) = (project(&pp, axis), project(&qp, axis));
+            if a.1 < b.0 - EPSILON || b.1 < a.0 - EPSILON {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// True if two convex polygons share at least one point.
+fn polygons_touch(p: &Polygon, q: &Polygon) -> bool {
+    let dq = plane_distances(q, &p.plane);
+    if dq.iter().all(|&d| d > EPSILON) || dq.iter().all(|&d| d < -EPSILON) {
+        return false;
+    }
+    if dq.iter().all(|&d| d.abs() <= EPSILON) {
+        return coplanar_overlap(p, q);
+    }
+
+    let dp = plane_distances(p, &q.plane);
+    if dp.iter().all(|&d| d > EPSILON) || dp.iter().all(|&d| d < -EPSILON) {
+        return false;
+    }
+
+    // Both polygons cross the line where the planes meet, check if they do so in the same place
+    let line = p.plane.0.cross(q.plane.0);
+    let length = line.length();
+    if length <= EPSILON {
+        return coplanar_overlap(p, q);
+    }
+    let line = line / length;
+
+    let a = project(&plane_crossing(p, &dp), line);
+    let b = project(&plane_crossing(q, &dq), line);
+    a.0 <= b.1 + EPSILON && b.0 <= a.1 + EPSILON
+}
+
+/// Closest distance between the segments `a0`-`a1` and `b0`-`b1`.
+fn segment_distance(a0: Vector, a1: Vector, b0: Vector, b1: Vector) -> Unit {
+    let (da, db, r) = (a1 - a0, b1 - b0, a0 - b0);
+    let (aa, bb, ab) = (da.dot(da), db.dot(db), da.dot(db));
+    let (ar, br) = (da.dot(r), db.dot(r));
+    let clamp = |t: Unit| t.clamp(0., 1.);
+
+    let (s, t) = if aa <= EPSILON * EPSILON && bb <= EPSILON * EPSILON {
+        (0., 0.)
+    } else if aa <= EPSILON * EPSILON {
+        (0., clamp(br / bb))
+    } else if bb <= EPSILON * EPSILON {
+        (clamp(-ar / aa), 0.)
+    } else {
+        // Closest points of the infinite lines, then clamped to the segments one at a time
+        let denom = aa * bb - ab * ab;
+        let s = if denom > EPSILON * EPSILON {
+            clamp((ab * br - ar * bb) / denom)
+        } else {
+            0.
+        };
+        let t = (ab * s + br) / bb;
+        if t < 0. {
+            (clamp(-ar / aa), 0.)
+        } else if t > 1. {
+            (clamp((ab - ar) / aa), 1.)
+        } else {
+            (s, t)
+        }
+    };
+
+    ((a0 + da * s) - (b0 + db * t)).length()
+}
+
+/// Distance between two convex polygons that do not touch. The closest points are then either
+/// a corner of one and the inside of the other, or on an edge of each.
+fn polygon_distance(p: &Polygon, q: &Polygon) -> Unit {
+    let corner_to_polygon = |corners: &Polygon, poly: &Polygon| {
+        corners
+            .vertices
+            .iter()
+            .map(|v| (poly.closest_point(v.position) - v.position).length())
+            .fold(Unit::MAX, Unit::min)
+    };
+
+    let mut best = corner_to_polygon(p, q).min(corner_to_polygon(q, p));
+
+    let (pl, ql) = (p.vertices.len(), q.vertices.len());
+    for i in 0..pl {
+        let (a0, a1) = (p.vertices[i].position, p.vertices[(i + 1) % pl].position);
+        for j in 0..ql {
+            let (b0, b1) = (q.vertices[j].position, q.vertices[(j + 1) % ql].position);
+            best = best.min(segment_distance(a0, a1, b0, b1));
+        }
+    }
+
+    best
+}
+
+impl Csg {
+    /// True if the solids share any point, touching counts. Stops at the first polygon of `a`
+    /// found crossing the surface of `b`, and only checks if one solid is inside of the other
+    /// when the surfaces do not meet. This is much cheaper than `Csg::intersect`.
+    pub fn intersects(a: &Csg, b: &Csg) -> bool {
+        if a.polygons.is_empty()
+            || b.polygons.is_empty()
+            || !a.bounding_box().intersects(&b.bounding_box())
+        {
+            return false;
+        }
+
+        let bvh = Bvh::new(b);
+        for p in &a.polygons {
+            let aabb = Aabb::from_points(p.vertices.iter().map(|v| v.position)).expand(EPSILON);
+            if bvh
+                .query_aabb(&aabb)
+                .iter()
+                .any(|&i| polygons_touch(p, &b.polygons[i]))
+            {
+                return true;
+            }
+        }
+
+        // The surfaces do not meet, so either solid is completely inside or outside the other
+        let inside = |csg: &Csg, other: &Csg| {
+            other.contains_point(csg.polygons[0].vertices[0].position) != Containment::Outside
+        };
+        inside(a, b) || inside(b, a)
+    }
+
+    /// Shortest distance between the surfaces of two solids, zero if they intersect. Solids
+    /// without polygons are infinitely far away, giving `Unit::MAX`.
+    pub fn min_distance(a: &Csg, b: &Csg) -> Unit {
+        if a.polygons.is_empty() || b.polygons.is_empty() {
+            return Unit::MAX;
+        }
+        if Csg::intersects(a, b) {
+            return 0.;
+        }
+
+        // Start from the distance of some corner, then only visit polygons that can beat it
+        let bvh = Bvh::new(b);
+        let mut best = bvh
+            .nearest_polygon(a.polygons[0].vertices[0].position)
+            .map_or(Unit::MAX, |n| n.1);
+
+        for p in &a.polygons {
+            let aabb = Aabb::from_points(p.vertices.iter().map(|v| v.position)).expand(best);
+            for i in bvh.query_aabb(&aabb) {
+                best = best.min(polygon_distance(p, &b.polygons[i]));
+            }
+        }
+
+        best
+    }
+
+    /// Volume shared by two solids, computed with `Csg::intersect` when `Csg::intersects` finds
+    /// that they overlap.
+    pub fn overlap_volume(a: &Csg, b: &Csg) -> Unit {
+        if Csg::intersects(a, b) {
+            Csg::intersect(a, b).volume()
+        } else {
+            0.
+        }
+    }
+}
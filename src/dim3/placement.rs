@@ -0,0 +1,56 @@
+use dim2::Point;
+use dim3::{Matrix4, Plane, Vector};
+use Unit;
+
+/// Places a 2D drawing in 3D space. The X and Y axes of the drawing are mapped to `x_axis` and
+/// `y_axis`, with its origo at `origin`. The axes are expected to be perpendicular unit vectors.
+#[derive(Clone, Copy, Debug)]
+pub struct Placement {
+    pub origin: Vector,
+    pub x_axis: Vector,
+    pub y_axis: Vector,
+}
+
+impl Placement {
+    pub fn new(origin: Vector, x_axis: Vector, y_axis: Vector) -> Placement {
+        Placement {
+            origin,
+            x_axis,
+            y_axis,
+        }
+    }
+
+    /// Parallel to the XY plane, at height `z`.
+    pub fn at_height(z: Unit) -> Placement {
+        Placement::new(Vector(0., 0., z), Vector(1., 0., 0.), Vector(0., 1., 0.))
+    }
+
+    /// Drawing on `plane`, with origo at the point of the plane closest to the 3D origo. This is
+    /// the frame that `Csg::section()` expresses its outlines in.
+    pub fn from_plane(plane: &Plane) -> Placement {
+        let normal = plane.0;
+        let x_axis = normal.make_orthogonal().normalize();
+
+        Placement::new(normal * plane.1, x_axis, normal.cross(x_axis))
+    }
+
+    /// Inverse of `place`, project `v` onto the drawing plane.
+    pub fn project(&self, v: Vector) -> Point {
+        let d = v - self.origin;
+        Point(d.dot(self.x_axis), d.dot(self.y_axis))
+    }
+
+    /// Normal of the drawing plane, the direction a counter clockwise outline faces.
+    pub fn normal(&self) -> Vector {
+        self.x_axis.cross(self.y_axis)
+    }
+
+    pub fn place(&self, p: Point) -> Vector {
+        self.origin + self.x_axis * p.0 + self.y_axis * p.1
+    }
+
+    /// Matrix taking the XY plane to the drawing plane, with Z along the normal.
+    pub fn matrix(&self) -> Matrix4 {
+        Matrix4::from_axes(self.x_axis, self.y_axis, self.normal(), self.origin)
+    }
+}
@@ -23,6 +23,20 @@ impl Plane {
         Plane(n, n.dot(v0))
     }
 
+    /// Same as `from_points`, but returns `None` when the points are (nearly) collinear and no
+    /// plane normal can be derived from them.
+    pub fn try_from_points(v0: Vector, v1: Vector, v2: Vector) -> Option<Plane> {
+        let cross = (v1 - v0).cross(v2 - v0);
+        let len = cross.length();
+
+        if len > EPSILON * EPSILON && len.is_finite() {
+            let n = cross / len;
+            Some(Plane(n, n.dot(v0)))
+        } else {
+            None
+        }
+    }
+
     pub fn flip(&self) -> Plane {
         Plane(self.0.negate(), -self.1)
     }
@@ -0,0 +1,161 @@
+use dim3::Csg;
+use dim3::{Polygon, Vector, Vertex};
+use Unit;
+
+/// Golden ratio
+const PHI: Unit = 1.618_034;
+
+/// Build a solid from corners and faces with outward (counter clockwise) winding, centered at
+/// origo and scaled so that every corner is at `radius`.
+fn from_faces(radius: Unit, corners: &[Vector], faces: &[&[usize]]) -> Csg {
+    Csg::from_polygons(
+        faces
+            .iter()
+            .map(|face| {
+                let normal = face
+                    .iter()
+                    .fold(Vector(0., 0., 0.), |acc, &i| acc + corners[i])
+                    .normalize();
+
+                Polygon::new(
+                    face.iter()
+                        .map(|&i| Vertex::new(corners[i].normalize() * radius, normal))
+                        .collect(),
+                )
+            })
+            .collect(),
+    )
+}
+
+pub(crate) fn icosahedron_corners() -> Vec<Vector> {
+    vec![
+        Vector(-1., PHI, 0.),
+        Vector(1., PHI, 0.),
+        Vector(-1., -PHI, 0.),
+        Vector(1., -PHI, 0.),
+        Vector(0., -1., PHI),
+        Vector(0., 1., PHI),
+        Vector(0., -1., -PHI),
+        Vector(0., 1., -PHI),
+        Vector(PHI, 0., -1.),
+        Vector(PHI, 0., 1.),
+        Vector(-PHI, 0., -1.),
+        Vector(-PHI, 0., 1.),
+    ]
+}
+
+pub(crate) const ICOSAHEDRON_FACES: [[usize; 3]; 20] = [
+    [0, 11, 5],
+    [0, 5, 1],
+    [0, 1, 7],
+    [0, 7, 10],
+    [0, 10, 11],
+    [1, 5, 9],
+    [5, 11, 4],
+    [11, 10, 2],
+    [10, 7, 6],
+    [7, 1, 8],
+    [3, 9, 4],
+    [3, 4, 2],
+    [3, 2, 6],
+    [3, 6, 8],
+    [3, 8, 9],
+    [4, 9, 5],
+    [2, 4, 11],
+    [6, 2, 10],
+    [8, 6, 7],
+    [9, 8, 1],
+];
+
+impl Csg {
+    /// Regular tetrahedron centered at origo, with corners at distance `radius`.
+    pub fn tetrahedron(radius: Unit) -> Csg {
+        from_faces(
+            radius,
+            &[
+                Vector(1., 1., 1.),
+                Vector(1., -1., -1.),
+                Vector(-1., 1., -1.),
+                Vector(-1., -1., 1.),
+            ],
+            &[&[0, 2, 3], &[0, 3, 1], &[0, 1, 2], &[1, 3, 2]],
+        )
+    }
+
+    /// Regular octahedron centered at origo, with corners at distance `radius`.
+    pub fn octahedron(radius: Unit) -> Csg {
+        from_faces(
+            radius,
+            &[
+                Vector(1., 0., 0.),
+                Vector(-1., 0., 0.),
+                Vector(0., 1., 0.),
+                Vector(0., -1., 0.),
+                Vector(0., 0., 1.),
+                Vector(0., 0., -1.),
+            ],
+            &[
+                &[0, 2, 4],
+                &[2, 1, 4],
+                &[1, 3, 4],
+                &[3, 0, 4],
+                &[2, 0, 5],
+                &[1, 2, 5],
+                &[3, 1, 5],
+                &[0, 3, 5],
+            ],
+        )
+    }
+
+    /// Regular icosahedron centered at origo, with corners at distance `radius`.
+    pub fn icosahedron(radius: Unit) -> Csg {
+        let faces: Vec<&[usize]> = ICOSAHEDRON_FACES.iter().map(|f| &f[..]).collect();
+        from_faces(radius, &icosahedron_corners(), &faces)
+    }
+
+    /// Regular dodecahedron centered at origo, with corners at distance `radius`. Faces are
+    /// pentagons.
+    pub fn dodecahedron(radius: Unit) -> Csg {
+        let inv = 1. / PHI;
+
+        from_faces(
+            radius,
+            &[
+                Vector(1., 1., 1.),
+                Vector(1., 1., -1.),
+                Vector(1., -1., 1.),
+                Vector(1., -1., -1.),
+                Vector(-1., 1., 1.),
+                Vector(-1., 1., -1.),
+                Vector(-1., -1., 1.),
+                Vector(-1., -1., -1.),
+                Vector(0., inv, PHI),
+                Vector(0., inv, -PHI),
+                Vector(0., -inv, PHI),
+                Vector(0., -inv, -PHI),
+                Vector(inv, PHI, 0.),
+                Vector(inv, -PHI, 0.),
+                Vector(-inv, PHI, 0.),
+                Vector(-inv, -PHI, 0.),
+                Vector(PHI, 0., inv),
+                Vector(PHI, 0., -inv),
+                Vector(-PHI, 0., inv),
+                Vector(-PHI, 0., -inv),
+            ],
+            &[
+                &[0, 8, 10, 2, 16],
+                &[0, 16, 17, 1, 12],
+                &[0, 12, 14, 4, 8],
+                &[1, 17, 3, 11, 9],
+                &[1, 9, 5, 14, 12],
+                &[2, 10, 6, 15, 13],
+                &[2, 13, 3, 17, 16],
+                &[3, 13, 15, 7, 11],
+                &[4, 14, 5, 19, 18],
+                &[4, 18, 6, 10, 8],
+                &[5, 9, 11, 7, 19],
+                &[6, 18, 19, 7, 15],
+            ],
+        )
+    }
+}
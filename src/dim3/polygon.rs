@@ -1,4 +1,5 @@
-use dim3::{Plane, Vertex};
+use dim3::containment::polygon_contains;
+use dim3::{Plane, Vector, Vertex};
 
 /// Represents a convex polygon. The vertices used to initialize a polygon must be coplanar and
 /// form a convex loop. They do not have to be `Vertex` instances but they must behave similarly
@@ -26,6 +27,21 @@ impl Polygon {
         Polygon { vertices, plane }
     }
 
+    /// Create a polygon, or `None` if there are fewer than three vertices or the first three
+    /// vertices are collinear.
+    pub fn try_new(vertices: Vec<Vertex>) -> Option<Polygon> {
+        if vertices.len() < 3 {
+            return None;
+        }
+
+        Plane::try_from_points(
+            vertices[0].position,
+            vertices[1].position,
+            vertices[2].position,
+        )
+        .map(|plane| Polygon { vertices, plane })
+    }
+
     pub fn flip(&mut self) {
         self.vertices.reverse();
 
@@ -35,4 +51,29 @@ impl Polygon {
 
         self.plane = self.plane.flip();
     }
+
+    /// The point of the polygon, including its inside, that is closest to `p`.
+    pub fn closest_point(&self, p: Vector) -> Vector {
+        let normal = self.plane.0;
+        let projected = p - normal * (normal.dot(p) - self.plane.1);
+        if polygon_contains(self, projected) {
+            return projected;
+        }
+
+        // Outside of the polygon, the closest point is on one of the edges
+        let len = self.vertices.len();
+        (0..len)
+            .map(|i| {
+                let a = self.vertices[i].position;
+                let edge = self.vertices[(i + 1) % len].position - a;
+                let length2 = edge.dot(edge);
+                if length2 > 0. {
+                    a + edge * ((p - a).dot(edge) / length2).clamp(0., 1.)
+                } else {
+                    a
+                }
+            })
+            .min_by(|x, y| (*x - p).length().partial_cmp(&(*y - p).length()).unwrap())
+            .unwrap()
+    }
 }
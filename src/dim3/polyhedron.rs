@@ -0,0 +1,155 @@
+use dim2::{triangulate, Point};
+use dim3::{Csg, Plane, Polygon, Vector, Vertex};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use EPSILON;
+
+/// Reasons `Csg::polyhedron` can reject its input. Faces are referred to by their index in the
+/// face list.
+#[derive(Debug, PartialEq)]
+pub enum PolyhedronError {
+    /// The face has fewer than three points.
+    TooFewPoints(usize),
+    /// The face refers to a point index (second value) that is not in the point list.
+    IndexOutOfRange(usize, usize),
+    /// All points of the face lie on a line.
+    DegenerateFace(usize),
+    /// The edge from one point index to the other is not matched by exactly one edge running the
+    /// opposite way, so the faces are open or do not all wind the same way.
+    UnmatchedEdge(usize, usize),
+}
+
+impl fmt::Display for PolyhedronError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PolyhedronError::TooFewPoints(face) => {
+                write!(f, "face {} has fewer than three points", face)
+            }
+            PolyhedronError::IndexOutOfRange(face, index) => {
+                write!(f, "face {} refers to missing point {}", face, index)
+            }
+            PolyhedronError::DegenerateFace(face) => write!(f, "face {} has no area", face),
+            PolyhedronError::UnmatchedEdge(from, to) => {
+                write!(f, "edge from point {} to {} has no opposite edge", from, to)
+            }
+        }
+    }
+}
+
+impl Error for PolyhedronError {}
+
+/// Normal of a possibly non-planar loop, using Newell's method. Not normalized.
+fn newell_normal(corners: &[Vector]) -> Vector {
+    let len = corners.len();
+    (0..len).fold(Vector(0., 0., 0.), |acc, i| {
+        let (a, b) = (corners[i], corners[(i + 1) % len]);
+        acc + Vector(
+            (a.1 - b.1) * (a.2 + b.2),
+            (a.2 - b.2) * (a.0 + b.0),
+            (a.0 - b.0) * (a.1 + b.1),
+        )
+    })
+}
+
+/// A planar loop where every corner turns the same way around `normal`.
+fn is_planar_convex(corners: &[Vector], normal: Vector) -> bool {
+    let len = corners.len();
+    let offset = normal.dot(corners[0]);
+
+    corners
+        .iter()
+        .all(|c| (normal.dot(*c) - offset).abs() <= EPSILON)
+        && (0..len).all(|i| {
+            let (a, b, c) = (corners[i], corners[(i + 1) % len], corners[(i + 2) % len]);
+            (b - a).cross(c - b).dot(normal) > -EPSILON * EPSILON
+        })
+}
+
+/// The plane is given rather than computed by `Polygon::new`, since the first three corners may
+/// be collinear.
+fn flat_polygon(corners: &[Vector], normal: Vector) -> Polygon {
+    Polygon {
+        vertices: corners.iter().map(|c| Vertex::new(*c, normal)).collect(),
+        plane: Plane(normal, normal.dot(corners[0])),
+    }
+}
+
+impl Csg {
+    /// Build a solid from a list of points and faces indexing into it, like OpenSCAD's
+    /// `polyhedron()`. The points of each face may be ordered clockwise when seen from the
+    /// outside, as in OpenSCAD, or counter clockwise, as long as all faces agree. The faces must
+    /// form a closed surface, where every edge is shared by two faces running it opposite ways.
+    /// A solid that comes out inside out, with a negative volume, is turned the right way.
+    ///
+    /// Faces that are not planar or not convex are split into triangles, since a `Polygon` has
+    /// to be convex.
+    pub fn polyhedron(points: &[Vector], faces: &[Vec<usize>]) -> Result<Csg, PolyhedronError> {
+        let mut polygons = Vec::with_capacity(faces.len());
+        let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+        // Six times the enclosed volume, from the tetrahedra between origo and each face
+        let mut volume = 0.;
+
+        for (face_index, face) in faces.iter().enumerate() {
+            if face.len() < 3 {
+                return Err(PolyhedronError::TooFewPoints(face_index));
+            }
+
+            let mut corners = Vec::with_capacity(face.len());
+            for &i in face {
+                match points.get(i) {
+                    Some(p) => corners.push(*p),
+                    None => return Err(PolyhedronError::IndexOutOfRange(face_index, i)),
+                }
+            }
+
+            for (k, &i) in face.iter().enumerate() {
+                *edges.entry((i, face[(k + 1) % face.len()])).or_insert(0) += 1;
+            }
+            for pair in corners[1..].windows(2) {
+                volume += corners[0].dot(pair[0].cross(pair[1]));
+            }
+
+            let normal = newell_normal(&corners);
+            let area = normal.length();
+            if area <= EPSILON * EPSILON || !area.is_finite() {
+                return Err(PolyhedronError::DegenerateFace(face_index));
+            }
+            let normal = normal / area;
+
+            if is_planar_convex(&corners, normal) {
+                polygons.push(flat_polygon(&corners, normal));
+                continue;
+            }
+
+            // Project onto the best fit plane, keeping counter clockwise order
+            let u = normal.make_orthogonal().normalize();
+            let v = normal.cross(u);
+            let projected: Vec<Point> = corners.iter().map(|c| Point(c.dot(u), c.dot(v))).collect();
+
+            for [a, b, c] in triangulate(&projected) {
+                let tri = [corners[a], corners[b], corners[c]];
+                // Triangles of a non-planar face each get their own normal
+                if let Some(plane) = Plane::try_from_points(tri[0], tri[1], tri[2]) {
+                    polygons.push(flat_polygon(&tri, plane.0));
+                }
+            }
+        }
+
+        for face in faces {
+            for (k, &from) in face.iter().enumerate() {
+                let to = face[(k + 1) % face.len()];
+                if edges[&(from, to)] != 1 || edges.get(&(to, from)) != Some(&1) {
+                    return Err(PolyhedronError::UnmatchedEdge(from, to));
+                }
+            }
+        }
+
+        let csg = Csg::from_polygons(polygons);
+        if volume < 0. {
+            Ok(csg.inverse())
+        } else {
+            Ok(csg)
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use dim2;
+use dim2::{LineStrip, Point};
+use dim3::Csg;
+use EPSILON;
+
+impl Csg {
+    /// Shadow of the solid on the XY plane, like OpenSCAD's `projection()`. The upward facing
+    /// polygons are projected and merged with `dim2::Csg::union`, giving the outline with holes
+    /// where nothing covers the plane.
+    pub fn project_xy(&self) -> dim2::Csg {
+        // Polygons are counter clockwise seen from above, the 2D outlines want clockwise
+        let mut shapes: Vec<dim2::Csg> = self
+            .polygons
+            .iter()
+            .filter(|poly| poly.plane.0 .2 > EPSILON)
+            .map(|poly| {
+                LineStrip::from_points(
+                    poly.vertices
+                        .iter()
+                        .rev()
+                        .map(|v| Point(v.position.0, v.position.1))
+                        .collect(),
+                )
+                .enclose()
+                .build()
+            })
+            .collect();
+
+        // Merge pairwise, so the BSP trees stay small for as long as possible
+        while shapes.len() > 1 {
+            let mut merged = Vec::new();
+            let mut iter = shapes.into_iter();
+
+            while let Some(a) = iter.next() {
+                merged.push(match iter.next() {
+                    Some(b) => dim2::Csg::union(&a, &b),
+                    None => a,
+                });
+            }
+
+            shapes = merged;
+        }
+
+        shapes
+            .pop()
+            .map_or_else(dim2::Csg::new, |shape| shape.simplify())
+    }
+}
@@ -0,0 +1,134 @@
+use dim3::{Csg, Matrix4, Vector};
+use std::ops::Mul;
+use {Unit, EPSILON, UNIT_PI};
+
+/// Rotation as a unit quaternion, `w + xi + yj + zk`. The product `a * b` rotates by `b` first
+/// and then by `a`, like `Matrix4`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion {
+    pub w: Unit,
+    pub x: Unit,
+    pub y: Unit,
+    pub z: Unit,
+}
+
+impl Quaternion {
+    pub fn new(w: Unit, x: Unit, y: Unit, z: Unit) -> Quaternion {
+        Quaternion { w, x, y, z }
+    }
+
+    pub fn identity() -> Quaternion {
+        Quaternion::new(1., 0., 0., 0.)
+    }
+
+    /// Rotation around `axis`, in the same direction as `Matrix4::rotation` and `Csg::rotate`.
+    /// The axis does not have to be normalized.
+    pub fn from_axis_angle(axis: Vector, angle_deg: Unit) -> Quaternion {
+        let axis = axis.normalize();
+        let (sin, cos) = (-angle_deg * UNIT_PI / 360.).sin_cos();
+        Quaternion::new(cos, axis.0 * sin, axis.1 * sin, axis.2 * sin)
+    }
+
+    /// Shortest rotation turning the direction `from` into the direction `to`. Opposite
+    /// directions are turned half a revolution around some axis perpendicular to them.
+    pub fn from_two_vectors(from: Vector, to: Vector) -> Quaternion {
+        let (from, to) = (from.normalize(), to.normalize());
+        let cos = from.dot(to);
+
+        if cos < -1. + EPSILON {
+            let axis = from.make_orthogonal().normalize();
+            return Quaternion::new(0., axis.0, axis.1, axis.2);
+        }
+
+        // Half way between identity and the full rotation, which halves the angle
+        let axis = from.cross(to);
+        Quaternion::new(1. + cos, axis.0, axis.1, axis.2).normalize()
+    }
+
+    pub fn dot(&self, other: Quaternion) -> Unit {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn length(&self) -> Unit {
+        self.dot(*self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Quaternion {
+        let inv = 1. / self.length();
+        Quaternion::new(self.w * inv, self.x * inv, self.y * inv, self.z * inv)
+    }
+
+    /// The opposite rotation, for unit quaternions.
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn rotate_vector(&self, v: Vector) -> Vector {
+        let q = Vector(self.x, self.y, self.z);
+        let t = q.cross(v) * 2.;
+        v + t * self.w + q.cross(t)
+    }
+
+    /// Spherical linear interpolation from `self` at `t = 0` to `other` at `t = 1`, turning at
+    /// constant speed along the shortest way.
+    pub fn slerp(&self, other: Quaternion, t: Unit) -> Quaternion {
+        // `q` and `-q` are the same rotation, pick the one closest to `self`
+        let (other, cos) = {
+            let cos = self.dot(other);
+            if cos < 0. {
+                (
+                    Quaternion::new(-other.w, -other.x, -other.y, -other.z),
+                    -cos,
+                )
+            } else {
+                (other, cos)
+            }
+        };
+
+        let (a, b) = if cos > 1. - EPSILON {
+            // Nearly the same rotation, where the angle can not be found accurately
+            (1. - t, t)
+        } else {
+            let angle = cos.acos();
+            let sin = angle.sin();
+            (((1. - t) * angle).sin() / sin, (t * angle).sin() / sin)
+        };
+
+        Quaternion::new(
+            self.w * a + other.w * b,
+            self.x * a + other.x * b,
+            self.y * a + other.y * b,
+            self.z * a + other.z * b,
+        )
+        .normalize()
+    }
+
+    pub fn to_matrix(&self) -> Matrix4 {
+        Matrix4::from_axes(
+            self.rotate_vector(Vector(1., 0., 0.)),
+            self.rotate_vector(Vector(0., 1., 0.)),
+            self.rotate_vector(Vector(0., 0., 1.)),
+            Vector(0., 0., 0.),
+        )
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        Quaternion::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+impl Csg {
+    /// Rotate the solid around origo by a quaternion.
+    pub fn rotate_quat(self, q: Quaternion) -> Csg {
+        self.transform(&q.normalize().to_matrix())
+    }
+}
@@ -0,0 +1,110 @@
+use dim3::containment::polygon_contains;
+use dim3::{Bvh, Csg, Polygon, Vector};
+use {Unit, EPSILON};
+
+/// Where a ray crosses the surface of a solid.
+#[derive(Clone, Copy, Debug)]
+pub struct Hit {
+    /// Distance along the ray, in multiples of the ray direction.
+    pub t: Unit,
+    pub point: Vector,
+    /// Normal of the polygon that was hit, which points away from the ray where it enters the
+    /// solid and along the ray where it leaves.
+    pub normal: Vector,
+    /// Index of the polygon in `Csg::polygons`.
+    pub polygon_index: usize,
+}
+
+fn ray_polygon(poly: &Polygon, index: usize, origin: Vector, dir: Vector) -> Option<Hit> {
+    let normal = poly.plane.0;
+    let along = normal.dot(dir);
+    if along.abs() <= EPSILON * EPSILON {
+        return None;
+    }
+
+    let t = (poly.plane.1 - normal.dot(origin)) / along;
+    if t < 0. {
+        return None;
+    }
+
+    let point = origin + dir * t;
+    if polygon_contains(poly, point) {
+        Some(Hit {
+            t,
+            point,
+            normal,
+            polygon_index: index,
+        })
+    } else {
+        None
+    }
+}
+
+impl Bvh {
+    /// First place the ray from `origin` along `dir` crosses the surface. The direction does not
+    /// have to be normalized.
+    pub fn raycast(&self, origin: Vector, dir: Vector) -> Option<Hit> {
+        let mut best: Option<Hit> = None;
+
+        self.ray_candidates(origin, dir, Unit::MAX, |i| {
+            if let Some(hit) = ray_polygon(&self.polygons()[i], i, origin, dir) {
+                let closer = match best {
+                    Some(b) => hit.t < b.t,
+                    None => true,
+                };
+                if closer {
+                    best = Some(hit);
+                }
+            }
+            best.map_or(Unit::MAX, |b| b.t)
+        });
+
+        best
+    }
+
+    /// Every place the ray crosses the surface, nearest first. A ray passing through an edge or
+    /// corner shared by several polygons gives a single hit.
+    pub fn raycast_all(&self, origin: Vector, dir: Vector) -> Vec<Hit> {
+        let mut hits: Vec<Hit> = Vec::new();
+
+        self.ray_candidates(origin, dir, Unit::MAX, |i| {
+            if let Some(hit) = ray_polygon(&self.polygons()[i], i, origin, dir) {
+                hits.push(hit);
+            }
+            Unit::MAX
+        });
+
+        hits.sort_by(|a, b| a.t.partial_cmp(&b.t).unwrap());
+
+        // Neighbouring polygons hit at the same spot, in the same direction, are one crossing
+        let tolerance = EPSILON / dir.length();
+        let mut crossings: Vec<Hit> = Vec::with_capacity(hits.len());
+        for hit in hits {
+            let duplicate = match crossings.last() {
+                Some(last) => {
+                    hit.t - last.t <= tolerance
+                        && (hit.normal.dot(dir) > 0.) == (last.normal.dot(dir) > 0.)
+                }
+                None => false,
+            };
+            if !duplicate {
+                crossings.push(hit);
+            }
+        }
+
+        crossings
+    }
+}
+
+impl Csg {
+    /// First place the ray from `origin` along `dir` crosses the surface. This builds a `Bvh` on
+    /// every call, build one with `Bvh::new` to cast many rays at the same solid.
+    pub fn raycast(&self, origin: Vector, dir: Vector) -> Option<Hit> {
+        Bvh::new(self).raycast(origin, dir)
+    }
+
+    /// Every place the ray crosses the surface, nearest first. See `Bvh::raycast_all`.
+    pub fn raycast_all(&self, origin: Vector, dir: Vector) -> Vec<Hit> {
+        Bvh::new(self).raycast_all(origin, dir)
+    }
+}
@@ -0,0 +1,76 @@
+use dim2;
+use dim2::Line;
+use dim3::{Csg, Placement, Plane, Vector};
+use {Unit, EPSILON};
+
+/// Where `polygon` crosses `plane`, as a pair of points. Vertices on the plane count as being in
+/// front of it, so an edge lying in the plane is only reported by the polygon behind it.
+fn cut_polygon(plane: &Plane, positions: &[Vector]) -> Option<(Vector, Vector)> {
+    let dists: Vec<Unit> = positions
+        .iter()
+        .map(|p| plane.0.dot(*p) - plane.1)
+        .collect();
+    let len = positions.len();
+    let mut points = Vec::with_capacity(2);
+
+    for i in 0..len {
+        let j = (i + 1) % len;
+        let (di, dj) = (dists[i], dists[j]);
+
+        if (di < -EPSILON) != (dj < -EPSILON) {
+            let point = if di.abs() <= EPSILON {
+                positions[i]
+            } else if dj.abs() <= EPSILON {
+                positions[j]
+            } else {
+                // Always interpolate from the same end, so neighbouring polygons sharing the
+                // edge get exactly the same point
+                let (a, b) = (positions[i], positions[j]);
+                if (a.0, a.1, a.2) < (b.0, b.1, b.2) {
+                    a.lerp(b, di / (di - dj))
+                } else {
+                    b.lerp(a, dj / (dj - di))
+                }
+            };
+            points.push(point);
+        }
+    }
+
+    // A convex polygon crosses the plane twice, if at all
+    if points.len() == 2 && (points[1] - points[0]).length() > EPSILON {
+        Some((points[0], points[1]))
+    } else {
+        None
+    }
+}
+
+impl Csg {
+    /// Cut the solid with `plane` and return the outline of the cut. The outline is expressed in
+    /// the 2D frame of `Placement::from_plane(plane)`, which can be used to map it back into 3D.
+    ///
+    /// Lines face out of the material, so outer outlines run clockwise and holes counter
+    /// clockwise, seen from the front of the plane.
+    pub fn section(&self, plane: &Plane) -> dim2::Csg {
+        let frame = Placement::from_plane(plane);
+        let mut lines: Vec<Line> = Vec::new();
+
+        for poly in &self.polygons {
+            let positions: Vec<Vector> = poly.vertices.iter().map(|v| v.position).collect();
+
+            if let Some((a, b)) = cut_polygon(plane, &positions) {
+                // Polygon normal projected on the plane is the outward direction of the cut,
+                // which should be to the left of the line
+                let along = poly.plane.0.cross(plane.0);
+                let (a, b) = if (b - a).dot(along) >= 0. {
+                    (a, b)
+                } else {
+                    (b, a)
+                };
+
+                lines.push(Line::new(frame.project(a), frame.project(b)));
+            }
+        }
+
+        dim2::Csg::from_lines(lines).simplify()
+    }
+}
@@ -1,5 +1,7 @@
+use dim3::platonic::{icosahedron_corners, ICOSAHEDRON_FACES};
 use dim3::Csg;
 use dim3::{Polygon, Vector, Vertex};
+use std::collections::HashMap;
 use {Unit, UNIT_PI};
 
 impl Csg {
@@ -41,8 +43,8 @@ impl Csg {
 
             polys.push(Polygon::new(vec![
                 make_vertex(radius, i0 * delta_theta, j0 * delta_phi),
-                make_vertex(radius, i1 * delta_theta, j0 * delta_phi),
                 make_vertex(radius, i0 * delta_theta, j1 * delta_phi),
+                make_vertex(radius, i1 * delta_theta, j0 * delta_phi),
             ]));
         }
 
@@ -65,30 +67,77 @@ impl Csg {
 
                 polys.push(Polygon::new(vec![
                     make_vertex(radius, i1 * delta_theta, j1 * delta_phi),
-                    make_vertex(radius, i2 * delta_theta, j2 * delta_phi),
                     make_vertex(radius, i0 * delta_theta, j2 * delta_phi),
+                    make_vertex(radius, i2 * delta_theta, j2 * delta_phi),
                 ]));
 
                 polys.push(Polygon::new(vec![
                     make_vertex(radius, i1 * delta_theta, j1 * delta_phi),
-                    make_vertex(radius, i0 * delta_theta, j0 * delta_phi),
                     make_vertex(radius, i2 * delta_theta, j0 * delta_phi),
+                    make_vertex(radius, i0 * delta_theta, j0 * delta_phi),
                 ]));
 
                 polys.push(Polygon::new(vec![
                     make_vertex(radius, i1 * delta_theta, j1 * delta_phi),
-                    make_vertex(radius, i0 * delta_theta, j2 * delta_phi),
                     make_vertex(radius, i0 * delta_theta, j0 * delta_phi),
+                    make_vertex(radius, i0 * delta_theta, j2 * delta_phi),
                 ]));
 
                 polys.push(Polygon::new(vec![
                     make_vertex(radius, i1 * delta_theta, j1 * delta_phi),
-                    make_vertex(radius, i2 * delta_theta, j0 * delta_phi),
                     make_vertex(radius, i2 * delta_theta, j2 * delta_phi),
+                    make_vertex(radius, i2 * delta_theta, j0 * delta_phi),
                 ]));
             }
         }
 
         Csg::from_polygons(polys)
     }
+
+    /// Sphere made by subdividing an icosahedron. Each subdivision splits every triangle into
+    /// four, giving `20 * 4^subdivisions` triangles of nearly equal size.
+    pub fn icosphere(radius: Unit, subdivisions: usize) -> Csg {
+        let mut corners: Vec<Vector> = icosahedron_corners()
+            .iter()
+            .map(|c| c.normalize())
+            .collect();
+        let mut faces: Vec<[usize; 3]> = ICOSAHEDRON_FACES.to_vec();
+
+        for _ in 0..subdivisions {
+            // Edges are shared by two triangles, make sure they share the midpoint too
+            let mut midpoints: HashMap<(usize, usize), usize> = HashMap::new();
+            let mut midpoint = |a: usize, b: usize| -> usize {
+                let key = if a < b { (a, b) } else { (b, a) };
+                let corners = &mut corners;
+                *midpoints.entry(key).or_insert_with(|| {
+                    corners.push(((corners[a] + corners[b]) * 0.5).normalize());
+                    corners.len() - 1
+                })
+            };
+
+            faces = faces
+                .iter()
+                .flat_map(|&[a, b, c]| {
+                    let ab = midpoint(a, b);
+                    let bc = midpoint(b, c);
+                    let ca = midpoint(c, a);
+
+                    vec![[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+                })
+                .collect();
+        }
+
+        Csg::from_polygons(
+            faces
+                .iter()
+                .map(|face| {
+                    Polygon::new(
+                        face.iter()
+                            .map(|&i| Vertex::new(corners[i] * radius, corners[i]))
+                            .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
 }
@@ -0,0 +1,263 @@
+use dim3::weld::Welder;
+use dim3::{Csg, Polygon, Vector, Vertex};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+use {Unit, EPSILON};
+
+const BINARY_HEADER: &[u8] = b"rscsg binary STL";
+const BINARY_HEADER_LEN: usize = 80;
+const BINARY_FACET_LEN: usize = 50;
+
+/// Errors from reading an STL file.
+#[derive(Debug)]
+pub enum StlError {
+    Io(io::Error),
+    /// The file ended before all facets announced in a binary header were read.
+    Truncated,
+    /// Malformed ASCII STL, with the offending line number (1-based).
+    Parse(usize, String),
+    /// The facet with the given index (0-based) has collinear or coincident corners.
+    DegenerateFacet(usize),
+}
+
+impl fmt::Display for StlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StlError::Io(e) => write!(f, "STL I/O error: {}", e),
+            StlError::Truncated => write!(f, "STL file is truncated"),
+            StlError::Parse(line, msg) => write!(f, "STL parse error on line {}: {}", line, msg),
+            StlError::DegenerateFacet(i) => write!(f, "STL facet {} is degenerate", i),
+        }
+    }
+}
+
+impl Error for StlError {}
+
+impl From<io::Error> for StlError {
+    fn from(e: io::Error) -> StlError {
+        StlError::Io(e)
+    }
+}
+
+fn write_u32_le<W: Write>(w: &mut W, value: u32) -> io::Result<()> {
+    w.write_all(&[
+        value as u8,
+        (value >> 8) as u8,
+        (value >> 16) as u8,
+        (value >> 24) as u8,
+    ])
+}
+
+fn write_vector_le<W: Write>(w: &mut W, v: Vector) -> io::Result<()> {
+    write_u32_le(w, v.0.to_bits())?;
+    write_u32_le(w, v.1.to_bits())?;
+    write_u32_le(w, v.2.to_bits())
+}
+
+fn read_u32_le(bytes: &[u8]) -> u32 {
+    (bytes[0] as u32)
+        | ((bytes[1] as u32) << 8)
+        | ((bytes[2] as u32) << 16)
+        | ((bytes[3] as u32) << 24)
+}
+
+fn read_vector_le(bytes: &[u8]) -> Vector {
+    Vector(
+        Unit::from_bits(read_u32_le(&bytes[0..4])),
+        Unit::from_bits(read_u32_le(&bytes[4..8])),
+        Unit::from_bits(read_u32_le(&bytes[8..12])),
+    )
+}
+
+/// A binary STL file has an exact size given by its triangle count, use that to tell it apart
+/// from ASCII files. Binary headers are allowed to start with `solid` too.
+fn is_binary(bytes: &[u8]) -> bool {
+    if bytes.len() >= BINARY_HEADER_LEN + 4 {
+        let count = read_u32_le(&bytes[BINARY_HEADER_LEN..]) as usize;
+        if BINARY_HEADER_LEN + 4 + count * BINARY_FACET_LEN == bytes.len() {
+            return true;
+        }
+    }
+
+    !bytes.starts_with(b"solid")
+}
+
+fn parse_binary(bytes: &[u8]) -> Result<Vec<[Vector; 3]>, StlError> {
+    if bytes.len() < BINARY_HEADER_LEN + 4 {
+        return Err(StlError::Truncated);
+    }
+
+    let count = read_u32_le(&bytes[BINARY_HEADER_LEN..]) as usize;
+    let body = &bytes[BINARY_HEADER_LEN + 4..];
+
+    if body.len() < count * BINARY_FACET_LEN {
+        return Err(StlError::Truncated);
+    }
+
+    Ok(body
+        .chunks(BINARY_FACET_LEN)
+        .take(count)
+        .map(|facet| {
+            // Skip the stored normal, it is recomputed from the winding
+            [
+                read_vector_le(&facet[12..24]),
+                read_vector_le(&facet[24..36]),
+                read_vector_le(&facet[36..48]),
+            ]
+        })
+        .collect())
+}
+
+fn parse_ascii(text: &str) -> Result<Vec<[Vector; 3]>, StlError> {
+    let mut facets = Vec::new();
+    let mut corners: Vec<Vector> = Vec::with_capacity(3);
+
+    for (i, line) in text.lines().enumerate() {
+        let line_nr = i + 1;
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next() {
+            Some("vertex") => {
+                let mut coord = || -> Result<Unit, StlError> {
+                    tokens
+                        .next()
+                        .and_then(|t| t.parse::<Unit>().ok())
+                        .ok_or_else(|| StlError::Parse(line_nr, "invalid vertex".to_string()))
+                };
+
+                let v = Vector(coord()?, coord()?, coord()?);
+                if corners.len() == 3 {
+                    return Err(StlError::Parse(line_nr, "too many vertices".to_string()));
+                }
+                corners.push(v);
+            }
+            Some("endloop") => {
+                if corners.len() != 3 {
+                    return Err(StlError::Parse(
+                        line_nr,
+                        format!("facet has {} vertices, expected 3", corners.len()),
+                    ));
+                }
+
+                facets.push([corners[0], corners[1], corners[2]]);
+                corners.clear();
+            }
+            _ => (),
+        }
+    }
+
+    Ok(facets)
+}
+
+fn read_facets<R: Read>(mut r: R) -> Result<Vec<[Vector; 3]>, StlError> {
+    let mut bytes = Vec::new();
+    r.read_to_end(&mut bytes)?;
+
+    if is_binary(&bytes) {
+        parse_binary(&bytes)
+    } else {
+        match String::from_utf8(bytes) {
+            Ok(text) => parse_ascii(&text),
+            Err(_) => Err(StlError::Parse(0, "invalid UTF-8".to_string())),
+        }
+    }
+}
+
+/// Weld facet corners and turn them into polygons. Degenerate facets are passed to `degenerate`,
+/// which decides whether to abort.
+fn build_polygons<F>(facets: Vec<[Vector; 3]>, mut degenerate: F) -> Result<Csg, StlError>
+where
+    F: FnMut(usize) -> Result<(), StlError>,
+{
+    let mut welder = Welder::new(EPSILON);
+    let mut polygons = Vec::with_capacity(facets.len());
+
+    for (i, facet) in facets.iter().enumerate() {
+        let positions = [
+            welder.weld(facet[0]),
+            welder.weld(facet[1]),
+            welder.weld(facet[2]),
+        ];
+
+        let vertices = positions
+            .iter()
+            .map(|p| Vertex::new(*p, Vector(0., 0., 0.)))
+            .collect();
+
+        match Polygon::try_new(vertices) {
+            Some(mut poly) => {
+                let normal = poly.plane.0;
+                for v in poly.vertices.iter_mut() {
+                    v.normal = normal;
+                }
+                polygons.push(poly);
+            }
+            None => degenerate(i)?,
+        }
+    }
+
+    Ok(Csg::from_polygons(polygons))
+}
+
+impl Csg {
+    /// Read an ASCII or binary STL file, the format is detected from the content. Corners closer
+    /// than `EPSILON` are welded together. Fails with `StlError::DegenerateFacet` if any facet
+    /// has no area.
+    pub fn from_stl(r: impl Read) -> Result<Csg, StlError> {
+        build_polygons(read_facets(r)?, |i| Err(StlError::DegenerateFacet(i)))
+    }
+
+    /// Same as `from_stl`, but degenerate facets are skipped instead. Their indices are returned
+    /// next to the result.
+    pub fn from_stl_lenient(r: impl Read) -> Result<(Csg, Vec<usize>), StlError> {
+        let mut skipped = Vec::new();
+        let csg = build_polygons(read_facets(r)?, |i| {
+            skipped.push(i);
+            Ok(())
+        })?;
+
+        Ok((csg, skipped))
+    }
+
+    /// Write all polygons as a binary STL file. Polygons are split into triangles using the same
+    /// fan triangulation as `get_triangles()`.
+    pub fn to_stl_binary(&self, mut w: impl Write) -> io::Result<()> {
+        let triangles = self.get_triangles();
+
+        let mut header = [0u8; BINARY_HEADER_LEN];
+        header[..BINARY_HEADER.len()].copy_from_slice(BINARY_HEADER);
+        w.write_all(&header)?;
+        write_u32_le(&mut w, triangles.len() as u32)?;
+
+        for tri in &triangles {
+            write_vector_le(&mut w, tri.normal)?;
+            for pos in tri.positions.iter() {
+                write_vector_le(&mut w, *pos)?;
+            }
+
+            // Attribute byte count, unused
+            w.write_all(&[0, 0])?;
+        }
+
+        Ok(())
+    }
+
+    /// Write all polygons as an ASCII STL file, with `name` as the name of the solid.
+    pub fn to_stl_ascii(&self, name: &str, mut w: impl Write) -> io::Result<()> {
+        writeln!(w, "solid {}", name)?;
+
+        for tri in self.get_triangles() {
+            let n = tri.normal;
+            writeln!(w, "  facet normal {:e} {:e} {:e}", n.0, n.1, n.2)?;
+            writeln!(w, "    outer loop")?;
+            for p in tri.positions.iter() {
+                writeln!(w, "      vertex {:e} {:e} {:e}", p.0, p.1, p.2)?;
+            }
+            writeln!(w, "    endloop")?;
+            writeln!(w, "  endfacet")?;
+        }
+
+        writeln!(w, "endsolid {}", name)
+    }
+}
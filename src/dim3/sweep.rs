@@ -0,0 +1,240 @@
+use dim2;
+use dim2::Point;
+use dim3::extrude::push_flat;
+use dim3::{Csg, ExtrudeError, Polygon, Vector};
+use {Unit, EPSILON, UNIT_PI};
+
+/// How the profile is oriented along the path of `Csg::sweep`.
+#[derive(Clone, Copy, Debug)]
+pub enum SweepFrame {
+    /// Rotate the profile as little as possible between path points, so it doesn't twist around
+    /// the path.
+    RotationMinimizing,
+    /// Keep the Y axis of the profile as close as possible to the given up direction, like a
+    /// handrail staying upright.
+    Fixed(Vector),
+}
+
+/// Placement of the profile at one path point. The profile X and Y axes span the plane halfway
+/// between the incoming and outgoing path directions. `stretch` widens the profile across a bend
+/// so the walls keep their thickness.
+struct Frame {
+    origin: Vector,
+    tangent: Vector,
+    x: Vector,
+    stretch: Option<(Vector, Unit)>,
+}
+
+impl Frame {
+    fn place(&self, p: Point) -> Vector {
+        let y = self.tangent.cross(self.x);
+        let mut offset = self.x * p.0 + y * p.1;
+
+        if let Some((dir, factor)) = self.stretch {
+            offset = offset + dir * (offset.dot(dir) * (factor - 1.));
+        }
+
+        self.origin + offset
+    }
+}
+
+/// Reflect `x` along the segment from `a` to `b`, then onto the next tangent. This is the double
+/// reflection method for rotation minimizing frames.
+fn reflect_frame(x: Vector, t0: Vector, t1: Vector, a: Vector, b: Vector) -> Vector {
+    let v1 = b - a;
+    let c1 = v1.dot(v1);
+    let x_l = x - v1 * (2. / c1 * v1.dot(x));
+    let t_l = t0 - v1 * (2. / c1 * v1.dot(t0));
+
+    let v2 = t1 - t_l;
+    let c2 = v2.dot(v2);
+    if c2 <= EPSILON * EPSILON {
+        x_l
+    } else {
+        x_l - v2 * (2. / c2 * v2.dot(x_l))
+    }
+}
+
+fn build_frames(path: &[Vector], closed: bool, mode: SweepFrame) -> Vec<Frame> {
+    let len = path.len();
+    let segment = |i: usize| (path[(i + 1) % len] - path[i]).normalize();
+
+    // Incoming and outgoing directions at each point
+    let directions: Vec<(Vector, Vector)> = (0..len)
+        .map(|i| {
+            let incoming = if i > 0 {
+                segment(i - 1)
+            } else if closed {
+                segment(len - 1)
+            } else {
+                segment(0)
+            };
+            let outgoing = if i + 1 < len || closed {
+                segment(i)
+            } else {
+                incoming
+            };
+
+            (incoming, outgoing)
+        })
+        .collect();
+
+    let tangents: Vec<Vector> = directions
+        .iter()
+        .map(|&(incoming, outgoing)| {
+            let sum = incoming + outgoing;
+            if sum.length() > EPSILON {
+                sum.normalize()
+            } else {
+                outgoing
+            }
+        })
+        .collect();
+
+    let mut xs: Vec<Vector> = Vec::with_capacity(len);
+    match mode {
+        SweepFrame::RotationMinimizing => {
+            xs.push(tangents[0].make_orthogonal().normalize());
+            for i in 1..len {
+                let x = reflect_frame(
+                    xs[i - 1],
+                    tangents[i - 1],
+                    tangents[i],
+                    path[i - 1],
+                    path[i],
+                );
+                xs.push(x.normalize());
+            }
+
+            // Going once around a closed path leaves the frame rotated, spread that rotation out
+            // evenly so the ends meet
+            if closed {
+                let back = reflect_frame(
+                    xs[len - 1],
+                    tangents[len - 1],
+                    tangents[0],
+                    path[len - 1],
+                    path[0],
+                );
+                let t = tangents[0];
+                let angle = back.cross(xs[0]).dot(t).atan2(back.dot(xs[0]));
+
+                // `angle` turns right handed around the tangent, `Vector::rotate` left handed
+                for (i, x) in xs.iter_mut().enumerate() {
+                    let deg = angle * 180. / UNIT_PI * (i as Unit) / (len as Unit);
+                    *x = x.rotate(tangents[i], -deg);
+                }
+            }
+        }
+        SweepFrame::Fixed(up) => {
+            for t in &tangents {
+                let y = up - *t * up.dot(*t);
+                let x = if y.length() > EPSILON {
+                    y.normalize().cross(*t)
+                } else {
+                    // Path runs along `up`, any orientation will do
+                    t.make_orthogonal()
+                };
+                xs.push(x.normalize());
+            }
+        }
+    }
+
+    (0..len)
+        .map(|i| {
+            let (incoming, outgoing) = directions[i];
+            let cos_half = incoming.dot(tangents[i]);
+            let across = incoming - outgoing;
+            let across = across - tangents[i] * across.dot(tangents[i]);
+
+            let stretch = if across.length() > EPSILON && cos_half > EPSILON {
+                Some((across.normalize(), 1. / cos_half))
+            } else {
+                None
+            };
+
+            Frame {
+                origin: path[i],
+                tangent: tangents[i],
+                x: xs[i],
+                stretch,
+            }
+        })
+        .collect()
+}
+
+impl Csg {
+    /// Sweep a 2D shape along a path of points. The profile X and Y axes are placed
+    /// perpendicular to the path as given by `frame_mode`, and bends are mitered. When the last
+    /// point of `path` equals the first, the path is treated as closed and the ends are joined.
+    ///
+    /// * `caps` - Close both ends of an open path. Without caps the result is a tube that is open
+    ///   at the ends, which is not a closed solid and should not be used in boolean operations
+    pub fn sweep(
+        shape: &dim2::Csg,
+        path: &[Vector],
+        frame_mode: SweepFrame,
+        caps: bool,
+    ) -> Result<Csg, ExtrudeError> {
+        let mut points: Vec<Vector> = path.to_vec();
+        points.dedup_by(|a, b| (*a - *b).length() <= EPSILON);
+
+        let closed = points.len() > 3 && (points[0] - points[points.len() - 1]).length() <= EPSILON;
+        if closed {
+            points.pop();
+        }
+
+        if points.len() < 2 {
+            return Err(ExtrudeError::PathTooShort);
+        }
+
+        let frames = build_frames(&points, closed, frame_mode);
+        let steps = if closed {
+            frames.len()
+        } else {
+            frames.len() - 1
+        };
+        let frame = |i: usize| &frames[i % frames.len()];
+
+        let mut polys: Vec<Polygon> = Vec::new();
+
+        for region in shape.to_regions() {
+            for outline in Some(&region.outer).into_iter().chain(region.holes.iter()) {
+                let len = outline.len();
+                for step in 0..steps {
+                    for i in 0..len {
+                        let (a, b) = (outline[i], outline[(i + 1) % len]);
+                        push_flat(
+                            &mut polys,
+                            vec![
+                                frame(step).place(a),
+                                frame(step).place(b),
+                                frame(step + 1).place(b),
+                                frame(step + 1).place(a),
+                            ],
+                        );
+                    }
+                }
+            }
+
+            if caps && !closed {
+                let (points, triangles) = region.triangulate();
+                for tri in triangles {
+                    push_flat(
+                        &mut polys,
+                        tri.iter()
+                            .rev()
+                            .map(|&i| frame(0).place(points[i]))
+                            .collect(),
+                    );
+                    push_flat(
+                        &mut polys,
+                        tri.iter().map(|&i| frame(steps).place(points[i])).collect(),
+                    );
+                }
+            }
+        }
+
+        Ok(Csg::from_polygons(polys))
+    }
+}
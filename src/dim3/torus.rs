@@ -0,0 +1,43 @@
+use dim3::Csg;
+use dim3::{Polygon, Vector, Vertex};
+use {Unit, UNIT_PI};
+
+impl Csg {
+    /// Torus around the Z axis, centered at origo. Empty if the tube is not thinner than the
+    /// ring, its radius is not positive, or there are fewer than three segments either way.
+    ///
+    /// * `major` - Distance from origo to the center of the tube
+    /// * `minor` - Radius of the tube
+    /// * `major_segments` - Number of segments around the Z axis
+    /// * `minor_segments` - Number of segments around the tube
+    pub fn torus(major: Unit, minor: Unit, major_segments: usize, minor_segments: usize) -> Csg {
+        if minor <= 0. || minor >= major || major_segments < 3 || minor_segments < 3 {
+            return Csg::new();
+        }
+
+        // Wrap indices so the seams get bit-for-bit identical positions
+        let make_vertex = |i: usize, j: usize| -> Vertex {
+            let u = UNIT_PI * 2. * ((i % major_segments) as Unit) / (major_segments as Unit);
+            let v = UNIT_PI * 2. * ((j % minor_segments) as Unit) / (minor_segments as Unit);
+            let out = Vector(u.cos() * v.cos(), u.sin() * v.cos(), v.sin());
+            let ring = Vector(u.cos(), u.sin(), 0.) * major;
+
+            Vertex::new(ring + out * minor, out)
+        };
+
+        let mut polys: Vec<Polygon> = Vec::new();
+
+        for i in 0..major_segments {
+            for j in 0..minor_segments {
+                polys.push(Polygon::new(vec![
+                    make_vertex(i, j),
+                    make_vertex(i + 1, j),
+                    make_vertex(i + 1, j + 1),
+                    make_vertex(i, j + 1),
+                ]));
+            }
+        }
+
+        Csg::from_polygons(polys)
+    }
+}
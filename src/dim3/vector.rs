@@ -50,7 +50,10 @@ impl Vector {
         )
     }
 
+    /// Rotate around `axis`, clockwise when the axis points towards the viewer. The axis does
+    /// not have to be normalized.
     pub fn rotate(&self, axis: Vector, angle_deg: Unit) -> Self {
+        let axis = axis.normalize();
         let va: Unit = self.dot(axis);
         let vprep = *self - axis * va;
         let vprep_len = vprep.length();
@@ -77,9 +80,20 @@ impl Vector {
         IVector(conv(self.0, step), conv(self.1, step), conv(self.2, step))
     }
 
-    /// Make a new vector which is orthogonal to `self`.
+    /// Make a new vector which is orthogonal to `self`. The result is not normalized.
     pub fn make_orthogonal(&self) -> Self {
-        *self
+        // Cross with the axis that is least parallel to `self`
+        let (x, y, z) = (self.0.abs(), self.1.abs(), self.2.abs());
+
+        let axis = if x <= y && x <= z {
+            Vector(1., 0., 0.)
+        } else if y <= z {
+            Vector(0., 1., 0.)
+        } else {
+            Vector(0., 0., 1.)
+        };
+
+        self.cross(axis)
     }
 }
 
@@ -0,0 +1,68 @@
+use dim3::Vector;
+use std::collections::HashMap;
+use Unit;
+
+/// Grid cell of a position. Wider than `IVector`, so that small tolerances can be used far from
+/// origo without the cells saturating.
+type Cell = (i64, i64, i64);
+
+/// Merges positions that are within `tolerance` of each other into the first of them that was
+/// seen, so that shared corners end up bit-for-bit identical. Positions are hashed into a grid
+/// with cells of `tolerance` size, and the neighbouring cells are searched as well, so close
+/// positions on either side of a cell border are found.
+pub(crate) struct Welder {
+    tolerance: Unit,
+    step: Unit,
+    cells: HashMap<Cell, Vec<usize>>,
+    pub positions: Vec<Vector>,
+}
+
+impl Welder {
+    pub fn new(tolerance: Unit) -> Welder {
+        Welder {
+            tolerance,
+            step: 1. / tolerance,
+            cells: HashMap::new(),
+            positions: Vec::new(),
+        }
+    }
+
+    fn cell(&self, v: Vector) -> Cell {
+        let conv = |x: Unit| (f64::from(x) * f64::from(self.step)).round() as i64;
+        (conv(v.0), conv(v.1), conv(v.2))
+    }
+
+    /// Index of the welded position for `v`.
+    pub fn index(&mut self, v: Vector) -> usize {
+        let cell = self.cell(v);
+
+        for x in -1..=1 {
+            for y in -1..=1 {
+                for z in -1..=1 {
+                    let neighbour = (
+                        cell.0.wrapping_add(x),
+                        cell.1.wrapping_add(y),
+                        cell.2.wrapping_add(z),
+                    );
+                    if let Some(indices) = self.cells.get(&neighbour) {
+                        for &i in indices {
+                            if (self.positions[i] - v).length() <= self.tolerance {
+                                return i;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        self.positions.push(v);
+        let index = self.positions.len() - 1;
+        self.cells.entry(cell).or_default().push(index);
+        index
+    }
+
+    pub fn weld(&mut self, v: Vector) -> Vector {
+        let i = self.index(v);
+        self.positions[i]
+    }
+}
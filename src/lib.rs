@@ -1,5 +1,6 @@
 pub mod dim2;
 pub mod dim3;
+pub mod slicer;
 
 #[macro_use]
 extern crate bitflags;
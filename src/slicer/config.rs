@@ -0,0 +1,35 @@
+use Unit;
+
+/// Settings for slicing and G-code output. Lengths are in millimeters, speeds in millimeters per
+/// second and temperatures in degrees Celsius.
+#[derive(Clone, Debug)]
+pub struct SlicerConfig {
+    pub layer_height: Unit,
+    /// Width of one extruded line, usually a bit more than the nozzle diameter.
+    pub extrusion_width: Unit,
+    /// Number of loops printed along each outline.
+    pub perimeters: usize,
+    /// Distance between infill lines. Equal to `extrusion_width` gives solid infill.
+    pub infill_spacing: Unit,
+    pub filament_diameter: Unit,
+    pub print_speed: Unit,
+    pub travel_speed: Unit,
+    pub nozzle_temperature: Unit,
+    pub bed_temperature: Unit,
+}
+
+impl Default for SlicerConfig {
+    fn default() -> SlicerConfig {
+        SlicerConfig {
+            layer_height: 0.2,
+            extrusion_width: 0.45,
+            perimeters: 2,
+            infill_spacing: 2.,
+            filament_diameter: 1.75,
+            print_speed: 50.,
+            travel_speed: 150.,
+            nozzle_temperature: 210.,
+            bed_temperature: 60.,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use dim2::Point;
+use slicer::{Layer, SlicerConfig};
+use std::io::{self, Write};
+use {Unit, UNIT_PI};
+
+/// Keeps track of the nozzle while writing moves.
+struct Toolhead<W: Write> {
+    w: W,
+    position: Point,
+    extruded: Unit,
+    /// Filament length pushed per millimeter of travel.
+    filament_per_mm: Unit,
+    print_feed: Unit,
+    travel_feed: Unit,
+}
+
+impl<W: Write> Toolhead<W> {
+    fn travel(&mut self, p: Point) -> io::Result<()> {
+        self.position = p;
+        writeln!(
+            self.w,
+            "G0 X{:.3} Y{:.3} F{:.0}",
+            p.0, p.1, self.travel_feed
+        )
+    }
+
+    fn extrude(&mut self, p: Point) -> io::Result<()> {
+        self.extruded += (p - self.position).length() * self.filament_per_mm;
+        self.position = p;
+        writeln!(
+            self.w,
+            "G1 X{:.3} Y{:.3} E{:.5} F{:.0}",
+            p.0, p.1, self.extruded, self.print_feed
+        )
+    }
+}
+
+/// Write the sliced layers as G-code, using absolute positions and absolute extrusion. The
+/// printer is homed and heated before the first layer, and cooled down after the last.
+pub fn write_gcode(layers: &[Layer], config: &SlicerConfig, w: impl Write) -> io::Result<()> {
+    let filament_radius = config.filament_diameter * 0.5;
+    let mut head = Toolhead {
+        w,
+        position: Point(0., 0.),
+        extruded: 0.,
+        filament_per_mm: config.extrusion_width * config.layer_height
+            / (UNIT_PI * filament_radius * filament_radius),
+        print_feed: config.print_speed * 60.,
+        travel_feed: config.travel_speed * 60.,
+    };
+
+    writeln!(head.w, "; generated by rscsg")?;
+    writeln!(head.w, "; layers: {}", layers.len())?;
+    writeln!(head.w, "M140 S{:.0}", config.bed_temperature)?;
+    writeln!(head.w, "M104 S{:.0}", config.nozzle_temperature)?;
+    writeln!(head.w, "M190 S{:.0}", config.bed_temperature)?;
+    writeln!(head.w, "M109 S{:.0}", config.nozzle_temperature)?;
+    writeln!(head.w, "G21")?;
+    writeln!(head.w, "G90")?;
+    writeln!(head.w, "M82")?;
+    writeln!(head.w, "G28")?;
+    writeln!(head.w, "G92 E0")?;
+
+    for (index, layer) in layers.iter().enumerate() {
+        writeln!(head.w, ";LAYER:{}", index)?;
+        writeln!(head.w, "G0 Z{:.3} F{:.0}", layer.z, head.travel_feed)?;
+
+        for perimeter in &layer.perimeters {
+            head.travel(perimeter[0])?;
+            for p in perimeter.iter().skip(1) {
+                head.extrude(*p)?;
+            }
+            head.extrude(perimeter[0])?;
+        }
+
+        for &(a, b) in &layer.infill {
+            head.travel(a)?;
+            head.extrude(b)?;
+        }
+    }
+
+    writeln!(head.w, "M104 S0")?;
+    writeln!(head.w, "M140 S0")?;
+    writeln!(head.w, "M84")?;
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use dim2::{Point, Region};
+use dim3::{Csg, Placement, Plane, Vector};
+use slicer::{offset_loop, SlicerConfig};
+use {Unit, UNIT_PI};
+
+/// Toolpaths for one layer of the print. All points are in the XY plane of the solid.
+#[derive(Clone)]
+pub struct Layer {
+    /// Height of the top of the layer above the bed.
+    pub z: Unit,
+    /// Closed loops, from the outline inwards for each region.
+    pub perimeters: Vec<Vec<Point>>,
+    /// Straight infill lines, ordered so that every other line runs backwards.
+    pub infill: Vec<(Point, Point)>,
+}
+
+fn rotate(p: Point, angle: Unit) -> Point {
+    let (sin, cos) = angle.sin_cos();
+    Point(p.0 * cos - p.1 * sin, p.0 * sin + p.1 * cos)
+}
+
+/// Section of the solid at height `z`, as regions in the XY plane.
+fn layer_regions(csg: &Csg, z: Unit) -> Vec<Region> {
+    let plane = Plane(Vector(0., 0., 1.), z);
+    let frame = Placement::from_plane(&plane);
+    let to_xy = |points: &[Point]| -> Vec<Point> {
+        points
+            .iter()
+            .map(|p| {
+                let v = frame.place(*p);
+                Point(v.0, v.1)
+            })
+            .collect()
+    };
+
+    // The frame is a rotation about Z, so loop orientation is kept
+    csg.section(&plane)
+        .to_regions()
+        .iter()
+        .map(|r| Region {
+            outer: to_xy(&r.outer),
+            holes: r.holes.iter().map(|h| to_xy(h)).collect(),
+        })
+        .collect()
+}
+
+/// Offset the outline and holes of a region inwards. Nothing is left when the outline vanishes,
+/// while vanished holes are just dropped.
+fn offset_region(region: &Region, distance: Unit) -> Option<Vec<Vec<Point>>> {
+    let mut loops = vec![offset_loop(&region.outer, distance)?];
+    loops.extend(
+        region
+            .holes
+            .iter()
+            .filter_map(|hole| offset_loop(hole, distance)),
+    );
+    Some(loops)
+}
+
+/// Fill the area inside `boundary`, by the even-odd rule, with lines `spacing` apart at `angle`
+/// radians from the X axis.
+fn rectilinear_infill(boundary: &[Vec<Point>], spacing: Unit, angle: Unit) -> Vec<(Point, Point)> {
+    let rotated: Vec<Vec<Point>> = boundary
+        .iter()
+        .map(|l| l.iter().map(|p| rotate(*p, -angle)).collect())
+        .collect();
+
+    let (mut min_y, mut max_y) = (Unit::MAX, Unit::MIN);
+    for p in rotated.iter().flat_map(|l| l.iter()) {
+        min_y = min_y.min(p.1);
+        max_y = max_y.max(p.1);
+    }
+    if min_y > max_y {
+        return Vec::new();
+    }
+
+    let mut lines = Vec::new();
+    // Scan lines sit on a fixed grid, so lines line up between regions and layers
+    let first = (min_y / spacing - 0.5).ceil() as i32;
+    let last = (max_y / spacing - 0.5).floor() as i32;
+
+    for row in first..=last {
+        let y = (row as Unit + 0.5) * spacing;
+        let mut xs: Vec<Unit> = Vec::new();
+
+        for l in &rotated {
+            let len = l.len();
+            for i in 0..len {
+                let (a, b) = (l[i], l[(i + 1) % len]);
+                if (a.1 > y) != (b.1 > y) {
+                    xs.push(a.0 + (y - a.1) / (b.1 - a.1) * (b.0 - a.0));
+                }
+            }
+        }
+
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut row_lines: Vec<(Point, Point)> = xs
+            .chunks(2)
+            .filter(|pair| pair.len() == 2)
+            .map(|pair| {
+                (
+                    rotate(Point(pair[0], y), angle),
+                    rotate(Point(pair[1], y), angle),
+                )
+            })
+            .collect();
+
+        if row % 2 != 0 {
+            row_lines.reverse();
+            for line in &mut row_lines {
+                *line = (line.1, line.0);
+            }
+        }
+        lines.extend(row_lines);
+    }
+
+    lines
+}
+
+/// Cut the solid into layers and plan the toolpaths of each. The lowest point of the solid is
+/// placed on the bed, while X and Y are kept as they are.
+///
+/// Every layer is sectioned at its middle height. Perimeters are offset inwards from the outline
+/// by half an extrusion width, then one width per loop, and the area left inside of them gets
+/// infill.
+pub fn slice(csg: &Csg, config: &SlicerConfig) -> Vec<Layer> {
+    let (mut min_z, mut max_z) = (Unit::MAX, Unit::MIN);
+    for polygon in &csg.polygons {
+        for vertex in &polygon.vertices {
+            min_z = min_z.min(vertex.position.2);
+            max_z = max_z.max(vertex.position.2);
+        }
+    }
+    if min_z >= max_z || config.layer_height <= 0. {
+        return Vec::new();
+    }
+
+    let width = config.extrusion_width;
+    let count = ((max_z - min_z) / config.layer_height).round().max(1.) as usize;
+    let mut layers = Vec::with_capacity(count);
+
+    for index in 0..count {
+        let bottom = index as Unit * config.layer_height;
+        let mut layer = Layer {
+            z: bottom + config.layer_height,
+            perimeters: Vec::new(),
+            infill: Vec::new(),
+        };
+
+        // Alternate between +45 and -45 degrees
+        let angle = if index % 2 == 0 { 0.25 } else { -0.25 } * UNIT_PI;
+
+        for region in layer_regions(csg, min_z + bottom + config.layer_height * 0.5) {
+            for perimeter in 0..config.perimeters {
+                let distance = width * (perimeter as Unit + 0.5);
+                if let Some(loops) = offset_region(&region, distance) {
+                    layer.perimeters.extend(loops);
+                }
+            }
+
+            if config.infill_spacing > 0. {
+                let distance = width * (config.perimeters as Unit + 0.5);
+                if let Some(boundary) = offset_region(&region, distance) {
+                    layer.infill.extend(rectilinear_infill(
+                        &boundary,
+                        config.infill_spacing,
+                        angle,
+                    ));
+                }
+            }
+        }
+
+        layers.push(layer);
+    }
+
+    layers
+}
@@ -0,0 +1,15 @@
+//! Slice a `dim3::Csg` into layers and write G-code for FDM printers.
+//!
+//! Each layer is a plane section of the solid. The outline of a layer is printed as a number of
+//! perimeters, made by offsetting the outline inwards, and the rest is filled with straight
+//! lines alternating between +45 and -45 degrees every layer.
+
+mod config;
+mod gcode;
+mod layer;
+mod offset;
+
+pub use self::config::SlicerConfig;
+pub use self::gcode::write_gcode;
+pub use self::layer::{slice, Layer};
+pub use self::offset::offset_loop;
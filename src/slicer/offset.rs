@@ -0,0 +1,71 @@
+use dim2::{signed_area, Point};
+use {Unit, EPSILON};
+
+/// A loop edge moved sideways, as a point on the line and the edge direction.
+#[derive(Clone, Copy)]
+struct OffsetEdge {
+    origin: Point,
+    direction: Point,
+}
+
+/// Where two consecutive offset edges meet. Parallel edges meet at the start of the second.
+fn corner(a: OffsetEdge, b: OffsetEdge) -> Point {
+    let cross = a.direction.0 * b.direction.1 - a.direction.1 * b.direction.0;
+    if cross.abs() < EPSILON {
+        return b.origin;
+    }
+    let d = b.origin - a.origin;
+    let t = (d.0 * b.direction.1 - d.1 * b.direction.0) / cross;
+    a.origin + a.direction * t
+}
+
+/// Move every edge of a closed loop `distance` to its left, joining the edges with mitered
+/// corners. For counter clockwise outlines and clockwise holes, left is into the material.
+///
+/// Edges that get turned around by the offset are dropped, letting their neighbours meet
+/// directly. Returns `None` when the loop vanishes. Self-intersections between parts of the loop
+/// that are not neighbours are not resolved.
+pub fn offset_loop(points: &[Point], distance: Unit) -> Option<Vec<Point>> {
+    let len = points.len();
+    let mut edges: Vec<OffsetEdge> = (0..len)
+        .filter_map(|i| {
+            let d = points[(i + 1) % len] - points[i];
+            if d.length() < EPSILON {
+                return None;
+            }
+            let direction = d.normalize();
+            Some(OffsetEdge {
+                origin: points[i] + direction.orthogonal() * distance,
+                direction,
+            })
+        })
+        .collect();
+
+    loop {
+        let count = edges.len();
+        if count < 3 {
+            return None;
+        }
+
+        let corners: Vec<Point> = (0..count)
+            .map(|i| corner(edges[(i + count - 1) % count], edges[i]))
+            .collect();
+
+        let flipped = (0..count)
+            .find(|&i| (corners[(i + 1) % count] - corners[i]).dot(edges[i].direction) <= 0.);
+
+        match flipped {
+            Some(i) => {
+                edges.remove(i);
+            }
+            None => {
+                let before = signed_area(points);
+                let after = signed_area(&corners);
+                if before * after <= 0. || after.abs() < EPSILON {
+                    return None;
+                }
+                return Some(corners);
+            }
+        }
+    }
+}
@@ -1,77 +1,65 @@
-use dim3::{Csg, IVector, Vector};
-use Unit;
+use dim3::{Aabb, Csg, Vector};
 
-pub struct BoundBox {
-    min: Vector,
-    max: Vector,
-    vertex_len: usize,
-}
+#[test]
+fn aabb_from_csg() {
+    // Every vertex counts, also those of the first polygon
+    let cube = Csg::cube(Vector(1., 2., 3.), false).translate(Vector(5., 5., 5.));
+    let bb = cube.bounding_box();
+    let (d_min, d_max) = bb.get_min_max_discreet(10.);
+    assert_eq!((50, 50, 50), (d_min.0, d_min.1, d_min.2));
+    assert_eq!((60, 70, 80), (d_max.0, d_max.1, d_max.2));
 
-impl BoundBox {
-    /// Create bounding box with origo `(0, 0, 0)` as starting point for maximum and minimum
-    /// borders.
-    pub fn origo() -> BoundBox {
-        BoundBox {
-            min: Vector(0., 0., 0.),
-            max: Vector(0., 0., 0.),
-            vertex_len: 0,
-        }
-    }
+    let (c, s) = (bb.center(), bb.size());
+    assert_eq!((5.5, 6., 6.5), (c.0, c.1, c.2));
+    assert_eq!((1., 2., 3.), (s.0, s.1, s.2));
+    assert_eq!(22., bb.surface_area());
 
-    fn on_vector(v: Vector) -> BoundBox {
-        BoundBox {
-            min: v,
-            max: v,
-            vertex_len: 1,
-        }
-    }
+    assert!(Csg::new().bounding_box().is_empty());
+    assert_eq!(0., Csg::new().bounding_box().surface_area());
+}
 
-    pub fn from_csg(csg: &Csg) -> BoundBox {
-        let polys = csg.to_polygons();
+fn assert_box(min: (f32, f32, f32), max: (f32, f32, f32), aabb: &Aabb) {
+    assert_eq!(min, (aabb.min.0, aabb.min.1, aabb.min.2));
+    assert_eq!(max, (aabb.max.0, aabb.max.1, aabb.max.2));
+}
 
-        if polys.len() > 0 && polys[0].vertices.len() > 0 {
-            let mut bb = BoundBox::on_vector(polys[0].vertices[0].position);
+#[test]
+fn aabb_operations() {
+    let a = Aabb::new(Vector(0., 0., 0.), Vector(2., 2., 2.));
+    let b = Aabb::new(Vector(1., 1., 1.), Vector(3., 4., 5.));
+    let far = Aabb::new(Vector(10., 0., 0.), Vector(11., 1., 1.));
 
-            for poly in polys.iter().skip(1) {
-                for vert in &poly.vertices {
-                    let pos = vert.position;
-                    bb.stretch(pos);
-                }
-            }
+    assert_box((0., 0., 0.), (3., 4., 5.), &Aabb::union(&a, &b));
+    assert_box((1., 1., 1.), (2., 2., 2.), &Aabb::intersection(&a, &b));
+    assert!(a.intersects(&b));
+    assert!(!a.intersects(&far));
+    assert!(Aabb::intersection(&a, &far).is_empty());
 
-            bb
-        } else {
-            BoundBox::origo()
-        }
-    }
+    // Touching boxes intersect
+    let touching = Aabb::new(Vector(2., 0., 0.), Vector(3., 1., 1.));
+    assert!(a.intersects(&touching));
 
-    pub fn stretch(&mut self, v: Vector) {
-        // Min
-        if v.0 < self.min.0 {
-            self.min.0 = v.0
-        }
-        if v.1 < self.min.1 {
-            self.min.1 = v.1
-        }
-        if v.2 < self.min.2 {
-            self.min.2 = v.2
-        }
+    assert!(a.contains(Vector(1., 2., 0.)));
+    assert!(!a.contains(Vector(1., 2.5, 0.)));
+    assert!(a.expand(0.5).contains(Vector(1., 2.5, 0.)));
+    assert!(a.expand(-1.5).is_empty());
+
+    // The empty box is neutral for union
+    assert_box((0., 0., 0.), (2., 2., 2.), &Aabb::union(&Aabb::empty(), &a));
+    assert!(!Aabb::empty().intersects(&a));
+}
 
-        // Max
-        if v.0 > self.max.0 {
-            self.max.0 = v.0
-        }
-        if v.1 > self.max.1 {
-            self.max.1 = v.1
-        }
-        if v.2 > self.max.2 {
-            self.max.2 = v.2
-        }
+#[test]
+fn boolean_disjoint() {
+    let a = Csg::cube(Vector(1., 1., 1.), false);
+    let b = Csg::cube(Vector(1., 1., 1.), false).translate(Vector(3., 0., 0.));
 
-        self.vertex_len = self.vertex_len + 1;
-    }
+    assert_eq!(12, Csg::union(&a, &b).polygons.len());
+    assert_eq!(6, Csg::subtract(&a, &b).polygons.len());
+    assert!(Csg::intersect(&a, &b).polygons.is_empty());
 
-    pub fn get_min_max_discreet(&self, div: Unit) -> (IVector, IVector) {
-        (self.min.discreet(div), self.max.discreet(div))
-    }
+    // An empty operand no longer drops the other solid
+    assert_eq!(6, Csg::union(&a, &Csg::new()).polygons.len());
+    assert_eq!(6, Csg::union(&Csg::new(), &a).polygons.len());
+    assert_eq!(6, Csg::subtract(&a, &Csg::new()).polygons.len());
 }
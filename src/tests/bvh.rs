@@ -0,0 +1,134 @@
+use dim3::{Aabb, Bvh, Csg, Vector};
+
+fn polygon_box(csg: &Csg, i: usize) -> Aabb {
+    Aabb::from_points(csg.polygons[i].vertices.iter().map(|v| v.position))
+}
+
+/// A sphere inside of a ring, without touching it.
+fn scene() -> Csg {
+    let mut polygons = Csg::icosphere(1., 3).polygons;
+    polygons.extend(
+        Csg::torus(2., 0.3, 24, 8)
+            .translate(Vector(0.5, 0., 0.))
+            .polygons,
+    );
+    Csg::from_polygons(polygons)
+}
+
+#[test]
+fn bvh_query_aabb() {
+    let csg = scene();
+    let bvh = Bvh::new(&csg);
+    assert_eq!(csg.bounding_box().min.0, bvh.bounding_box().min.0);
+    assert_eq!(csg.bounding_box().max.2, bvh.bounding_box().max.2);
+
+    let queries = [
+        Aabb::new(Vector(0.5, 0.5, 0.5), Vector(1., 1., 1.)),
+        Aabb::new(Vector(-3., -0.1, -0.1), Vector(3., 0.1, 0.1)),
+        Aabb::new(Vector(5., 5., 5.), Vector(6., 6., 6.)),
+    ];
+
+    for query in &queries {
+        let mut found = bvh.query_aabb(query);
+        found.sort();
+        let expected: Vec<usize> = (0..csg.polygons.len())
+            .filter(|&i| polygon_box(&csg, i).intersects(query))
+            .collect();
+        assert_eq!(expected, found);
+    }
+}
+
+#[test]
+fn bvh_query_ray() {
+    let csg = scene();
+    let bvh = Bvh::new(&csg);
+    let (origin, dir) = (Vector(-4., 0.1, 0.05), Vector(1., 0., 0.));
+
+    let candidates = bvh.query_ray(origin, dir);
+    assert!(candidates.len() < csg.polygons.len() / 4);
+
+    // Every polygon that is hit is among the candidates
+    let hits = bvh.raycast_all(origin, dir);
+    assert_eq!(6, hits.len());
+    for hit in &hits {
+        assert!(candidates.contains(&hit.polygon_index));
+    }
+}
+
+#[test]
+fn bvh_query_ray_axis_aligned() {
+    let csg = scene();
+    let bvh = Bvh::new(&csg);
+    let coords = |v: Vector| [v.0, v.1, v.2];
+
+    // Rays along the axes through corners, so they lie in the sides of many polygon boxes
+    let axes = [
+        (0, Vector(1., 0., 0.)),
+        (1, Vector(0., 1., 0.)),
+        (2, Vector(0., 0., -1.)),
+    ];
+    for k in (0..csg.polygons.len()).step_by(37) {
+        let corner = csg.polygons[k].vertices[0].position;
+        for &(axis, dir) in &axes {
+            let origin = corner - dir * 5.;
+            let mut found = bvh.query_ray(origin, dir);
+            found.sort();
+
+            // Brute force, the box must be around the ray across the axis and not behind it
+            let expected: Vec<usize> = (0..csg.polygons.len())
+                .filter(|&i| {
+                    let aabb = polygon_box(&csg, i);
+                    let (min, max, o) = (coords(aabb.min), coords(aabb.max), coords(origin));
+                    let around = (0..3).all(|a| a == axis || (min[a] <= o[a] && o[a] <= max[a]));
+                    let ahead = aabb.min.dot(dir).max(aabb.max.dot(dir)) >= origin.dot(dir);
+                    around && ahead
+                })
+                .collect();
+            assert!(expected.contains(&k));
+            assert_eq!(expected, found);
+        }
+    }
+}
+
+#[test]
+fn bvh_nearest_polygon() {
+    let csg = scene();
+    let bvh = Bvh::new(&csg);
+
+    for i in 0..30 {
+        let a = i as f32 * 0.7;
+        let p = Vector(a.cos() * 3., a.sin() * 2., (i as f32 * 0.2) - 3.);
+
+        let (point, distance, index) = bvh.nearest_polygon(p).unwrap();
+        let brute = csg
+            .polygons
+            .iter()
+            .map(|poly| (poly.closest_point(p) - p).length())
+            .fold(f32::MAX, f32::min);
+
+        assert!((distance - brute).abs() < 0.0001);
+        assert!(((point - p).length() - distance).abs() < 0.0001);
+        let poly = &csg.polygons[index];
+        assert!((poly.plane.0.dot(point) - poly.plane.1).abs() < 0.0001);
+    }
+
+    assert!(Bvh::new(&Csg::new())
+        .nearest_polygon(Vector(0., 0., 0.))
+        .is_none());
+}
+
+#[test]
+fn polygon_closest_point() {
+    let cube = Csg::cube(Vector(2., 2., 2.), true);
+    // The +X face
+    let face = cube.polygons.iter().find(|p| p.plane.0 .0 > 0.5).unwrap();
+
+    let inside = face.closest_point(Vector(3., 0.5, -0.5));
+    assert!((inside - Vector(1., 0.5, -0.5)).length() < 0.0001);
+
+    let edge = face.closest_point(Vector(3., 3., 0.));
+    assert!((edge - Vector(1., 1., 0.)).length() < 0.0001);
+
+    let corner = face.closest_point(Vector(3., 3., -3.));
+    assert!((corner - Vector(1., 1., -1.)).length() < 0.0001);
+}
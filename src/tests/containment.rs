@@ -0,0 +1,103 @@
+use dim3::{BspNode, Containment, Csg, Vector};
+
+#[test]
+fn contains_cube() {
+    let cube = Csg::cube(Vector(2., 2., 2.), true);
+
+    assert_eq!(Containment::Inside, cube.contains_point(Vector(0., 0., 0.)));
+    assert_eq!(
+        Containment::Inside,
+        cube.contains_point(Vector(0.9, -0.9, 0.9))
+    );
+    assert_eq!(
+        Containment::Outside,
+        cube.contains_point(Vector(1.1, 0., 0.))
+    );
+    assert_eq!(
+        Containment::Outside,
+        cube.contains_point(Vector(5., 5., 5.))
+    );
+
+    // Faces, edges and corners
+    assert_eq!(
+        Containment::OnSurface,
+        cube.contains_point(Vector(1., 0.2, 0.3))
+    );
+    assert_eq!(
+        Containment::OnSurface,
+        cube.contains_point(Vector(1., -1., 0.5))
+    );
+    assert_eq!(
+        Containment::OnSurface,
+        cube.contains_point(Vector(-1., -1., 1.))
+    );
+
+    // In the plane of a face, but outside of the cube
+    assert_eq!(
+        Containment::Outside,
+        cube.contains_point(Vector(1., 3., 0.))
+    );
+
+    assert_eq!(
+        Containment::Outside,
+        Csg::new().contains_point(Vector(0., 0., 0.))
+    );
+}
+
+#[test]
+fn contains_tube() {
+    let tube = Csg::subtract(
+        &Csg::cylinder(Vector(0., 0., 0.), Vector(0., 0., 2.), 2., 16),
+        &Csg::cylinder(Vector(0., 0., -1.), Vector(0., 0., 3.), 1., 16),
+    );
+    let tree = BspNode::new(Some(tube.polygons.clone()));
+
+    assert_eq!(
+        Containment::Outside,
+        tree.classify_point(Vector(0., 0., 1.))
+    );
+    assert_eq!(
+        Containment::Inside,
+        tree.classify_point(Vector(1.5, 0., 1.))
+    );
+    assert_eq!(
+        Containment::Inside,
+        tree.classify_point(Vector(0., -1.5, 0.5))
+    );
+    assert_eq!(
+        Containment::Outside,
+        tree.classify_point(Vector(1.5, 0., 2.5))
+    );
+    assert_eq!(
+        Containment::OnSurface,
+        tree.classify_point(Vector(1.5, 0., 2.))
+    );
+
+    // The prebuilt tree agrees with the one shot query
+    for i in 0..20 {
+        let p = Vector(i as f32 * 0.13 - 1.3, 0.1, 1.);
+        assert_eq!(tube.contains_point(p), tree.classify_point(p));
+    }
+}
+
+#[test]
+fn contains_sphere() {
+    let sphere = Csg::icosphere(1., 2);
+
+    assert_eq!(
+        Containment::Inside,
+        sphere.contains_point(Vector(0., 0., 0.))
+    );
+    assert_eq!(
+        Containment::Inside,
+        sphere.contains_point(Vector(0.5, 0.5, 0.5))
+    );
+    assert_eq!(
+        Containment::Outside,
+        sphere.contains_point(Vector(0.7, 0.7, 0.7))
+    );
+    assert_eq!(
+        Containment::OnSurface,
+        sphere.contains_point(Vector(0., 0., 1.))
+    );
+}
@@ -1,7 +1,79 @@
-use dim2::{circle, rectangle, Point};
+use dim2::{circle, rectangle, signed_area, triangulate, Csg, Point};
 
 #[test]
 fn dim2_shapes() {
     let _r = rectangle(Point(0f32, 0f32), Point(4f32, 3f32));
     let _s = circle(Point(0f32, 0f32), 1f32, 8);
 }
+
+#[test]
+fn dim2_triangulate() {
+    // Counter clockwise U shape
+    let points = vec![
+        Point(0., 0.),
+        Point(3., 0.),
+        Point(3., 2.),
+        Point(2., 2.),
+        Point(2., 1.),
+        Point(1., 1.),
+        Point(1., 2.),
+        Point(0., 2.),
+    ];
+
+    let triangles = triangulate(&points);
+    assert_eq!(points.len() - 2, triangles.len());
+
+    let area: f32 = triangles
+        .iter()
+        .map(|t| signed_area(&[points[t[0]], points[t[1]], points[t[2]]]))
+        .sum();
+    assert!((area - signed_area(&points)).abs() < 0.0001);
+    assert!(triangles
+        .iter()
+        .all(|t| signed_area(&[points[t[0]], points[t[1]], points[t[2]]]) > 0.));
+
+    // Same shape clockwise keeps its orientation
+    let reversed: Vec<Point> = points.iter().rev().cloned().collect();
+    assert!(triangulate(&reversed).iter().all(|t| signed_area(&[
+        reversed[t[0]],
+        reversed[t[1]],
+        reversed[t[2]]
+    ]) < 0.));
+}
+
+#[test]
+fn dim2_regions() {
+    let rect = rectangle(Point(0., 0.), Point(4., 3.));
+    assert_eq!(4, rect.lines.len());
+    assert_eq!(1, rect.to_loops().len());
+
+    // Ring with an island inside its hole
+    let mut lines = circle(Point(0., 0.), 3., 16).to_lines();
+    lines.append(&mut circle(Point(0., 0.), 2., 16).to_lines());
+    lines.append(&mut rectangle(Point(-1., -1.), Point(1., 1.)).to_lines());
+    let regions = Csg::from_lines(lines).to_regions();
+
+    assert_eq!(2, regions.len());
+    assert_eq!(16, regions[0].outer.len());
+    assert_eq!(1, regions[0].holes.len());
+    assert!(signed_area(&regions[0].outer) > 0.);
+    assert!(signed_area(&regions[0].holes[0]) < 0.);
+    assert_eq!(4, regions[1].outer.len());
+    assert!(signed_area(&regions[1].outer) > 0.);
+    assert!(regions[1].holes.is_empty());
+}
+
+#[test]
+fn dim2_union_overlap() {
+    let a = rectangle(Point(0., 0.), Point(2., 2.));
+    let b = rectangle(Point(1., 1.), Point(3., 3.));
+    let union = Csg::union(&a, &b);
+
+    let loops = union.to_loops();
+    assert_eq!(1, loops.len());
+    assert!((signed_area(&loops[0]) + 2. * 7.).abs() < 0.0001);
+    assert_eq!(8, union.simplify().to_loops()[0].len());
+
+    // Union with nothing keeps the shape
+    assert_eq!(4, Csg::union(&a, &Csg::new()).lines.len());
+}
@@ -0,0 +1,48 @@
+use dim3::{BspNode, Bvh, Csg, Vector};
+
+#[test]
+fn closest_point_cube() {
+    let cube = Csg::cube(Vector(2., 2., 2.), true);
+
+    let (point, distance, index) = cube.closest_point(Vector(3., 0.5, 0.)).unwrap();
+    assert!((point - Vector(1., 0.5, 0.)).length() < 0.0001);
+    assert!((distance - 2.).abs() < 0.0001);
+    assert!(cube.polygons[index].plane.0 .0 > 0.99);
+
+    let (point, distance, _) = cube.closest_point(Vector(2., 2., 2.)).unwrap();
+    assert!((point - Vector(1., 1., 1.)).length() < 0.0001);
+    assert!((distance - 3f32.sqrt()).abs() < 0.0001);
+
+    assert!(Csg::new().closest_point(Vector(0., 0., 0.)).is_none());
+}
+
+#[test]
+fn signed_distance_cube() {
+    let cube = Csg::cube(Vector(2., 2., 2.), true);
+
+    assert!((cube.signed_distance(Vector(0., 0., 0.)) + 1.).abs() < 0.0001);
+    assert!((cube.signed_distance(Vector(0.5, 0., 0.2)) + 0.5).abs() < 0.0001);
+    assert!((cube.signed_distance(Vector(0., -4., 0.)) - 3.).abs() < 0.0001);
+    assert!(cube.signed_distance(Vector(1., 0., 0.)).abs() < 0.0001);
+    assert_eq!(f32::MAX, Csg::new().signed_distance(Vector(0., 0., 0.)));
+}
+
+#[test]
+fn signed_distance_prebuilt() {
+    let tube = Csg::subtract(
+        &Csg::cylinder(Vector(0., 0., 0.), Vector(0., 0., 2.), 2., 32),
+        &Csg::cylinder(Vector(0., 0., -1.), Vector(0., 0., 3.), 1., 32),
+    );
+    let bvh = Bvh::new(&tube);
+    let bsp = BspNode::new(Some(tube.polygons.clone()));
+
+    for i in 0..20 {
+        let p = Vector(i as f32 * 0.15 - 1.5, 0.02, 1.);
+        let d = Csg::signed_distance_with(&bvh, &bsp, p);
+        assert_eq!(tube.signed_distance(p), d);
+
+        // Inside the wall between radius 1 and 2 the distance is negative
+        let r = p.0.hypot(p.1);
+        assert_eq!(r > 1.01 && r < 1.95, d < 0., "radius {} distance {}", r, d);
+    }
+}
@@ -0,0 +1,373 @@
+use super::primitives::{assert_closed, assert_outward};
+use dim2::{self, circle, rectangle, LineStrip, Point};
+use dim3::{Csg, ExtrudeError, Placement, SweepFrame, Vector};
+
+/// Square with a square hole, with both loops running the same direction.
+fn frame() -> dim2::Csg {
+    let mut lines = rectangle(Point(-2., -2.), Point(2., 2.)).to_lines();
+    lines.append(&mut rectangle(Point(-1., -1.), Point(1., 1.)).to_lines());
+    dim2::Csg::from_lines(lines)
+}
+
+#[test]
+fn linear_extrude_rectangle() {
+    let shape = rectangle(Point(0., 0.), Point(2., 1.));
+    let solid = Csg::linear_extrude(&shape, 3., 0., 1, Point(1., 1.), false);
+
+    // Four walls and two triangles per cap
+    assert_eq!(4 + 2 * 2, solid.polygons.len());
+    assert_closed(&solid);
+
+    let (d_min, d_max) = solid.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((0, 0, 0), (d_min.0, d_min.1, d_min.2));
+    assert_eq!((20, 10, 30), (d_max.0, d_max.1, d_max.2));
+
+    // Caps face down and up
+    assert!(solid.polygons.iter().any(|p| p.plane.0 .2 < -0.99));
+    assert!(solid.polygons.iter().any(|p| p.plane.0 .2 > 0.99));
+}
+
+#[test]
+fn linear_extrude_hole() {
+    let solid = Csg::linear_extrude(&frame(), 1., 0., 1, Point(1., 1.), true);
+
+    // Eight walls, the caps are ten bridged corners split into eight triangles
+    assert_eq!(8 + 2 * 8, solid.polygons.len());
+    assert_closed(&solid);
+
+    // Nothing covers the hole
+    for poly in solid.polygons.iter().filter(|p| p.plane.0 .2.abs() > 0.99) {
+        let mid = poly.vertices.iter().fold(Point(0., 0.), |acc, v| {
+            acc + Point(v.position.0, v.position.1)
+        }) / 3.;
+        assert!(mid.0.abs() > 1. || mid.1.abs() > 1.);
+    }
+}
+
+#[test]
+fn linear_extrude_twist_scale() {
+    let shape = circle(Point(0., 0.), 1., 12);
+    let solid = Csg::linear_extrude(&shape, 2., 90., 8, Point(0.5, 0.5), false);
+    assert_closed(&solid);
+
+    let (d_min, d_max) = solid.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((-10, -10, 0), (d_min.0, d_min.1, d_min.2));
+    assert_eq!((10, 10, 20), (d_max.0, d_max.1, d_max.2));
+
+    // Pointy top, nothing but walls and a bottom cap
+    let cone = Csg::linear_extrude(&shape, 1., 0., 1, Point(0., 0.), false);
+    assert_closed(&cone);
+    assert_eq!(12 + 10, cone.polygons.len());
+}
+
+#[test]
+fn linear_extrude_open_outline() {
+    // Lines that don't form a loop are ignored
+    let shape = LineStrip::new()
+        .line_to(Point(0., 0.))
+        .line_to(Point(1., 0.))
+        .line_to(Point(1., 1.))
+        .build();
+
+    let solid = Csg::linear_extrude(&shape, 1., 0., 1, Point(1., 1.), false);
+    assert_eq!(0, solid.polygons.len());
+}
+
+#[test]
+fn linear_extrude_downwards() {
+    let shape = rectangle(Point(0., 0.), Point(2., 1.));
+    let solid = Csg::linear_extrude(&shape, -3., 30., 4, Point(0.5, 0.5), false);
+    assert_closed(&solid);
+    assert_outward(&solid, Vector(0.5, 0.25, -1.5));
+    assert!(solid.volume() > 0.);
+
+    let (d_min, d_max) = solid.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((-30, 0), (d_min.2, d_max.2));
+
+    // Same volume as extruding upwards
+    let up = Csg::linear_extrude(&shape, 3., 30., 4, Point(0.5, 0.5), false);
+    assert!((solid.volume() - up.volume()).abs() < 0.0001);
+}
+
+#[test]
+fn rotate_extrude_ring() {
+    // Square profile revolved into a washer
+    let profile = rectangle(Point(1., 0.), Point(2., 1.));
+    let washer = Csg::rotate_extrude(&profile, 360., 16).unwrap();
+
+    assert_eq!(4 * 16, washer.polygons.len());
+    assert_closed(&washer);
+
+    let (d_min, d_max) = washer.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((-20, -20, 0), (d_min.0, d_min.1, d_min.2));
+    assert_eq!((20, 20, 10), (d_max.0, d_max.1, d_max.2));
+
+    // Inner wall faces the axis, outer wall faces away from it
+    for poly in &washer.polygons {
+        let radii: Vec<f32> = poly
+            .vertices
+            .iter()
+            .map(|v| v.position.0.hypot(v.position.1))
+            .collect();
+        let p = poly.vertices[0].position;
+        let outward = poly.plane.0.dot(Vector(p.0, p.1, 0.));
+
+        if radii.iter().all(|r| (r - 1.).abs() < 0.001) {
+            assert!(outward < 0.);
+        } else if radii.iter().all(|r| (r - 2.).abs() < 0.001) {
+            assert!(outward > 0.);
+        }
+    }
+}
+
+#[test]
+fn rotate_extrude_partial() {
+    // Half disc touching the axis, revolved a quarter turn
+    let profile = LineStrip::new()
+        .line_to(Point(0., 0.))
+        .line_to(Point(1., 0.))
+        .line_to(Point(1., 1.))
+        .line_to(Point(0., 1.))
+        .enclose()
+        .build();
+
+    let wedge = Csg::rotate_extrude(&profile, 90., 4).unwrap();
+    assert_closed(&wedge);
+
+    let (d_min, d_max) = wedge.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((0, 0, 0), (d_min.0, d_min.1, d_min.2));
+    assert_eq!((10, 10, 10), (d_max.0, d_max.1, d_max.2));
+
+    // Profile on the negative side is mirrored
+    let mirrored = Csg::rotate_extrude(&profile.clone().scale(Point(-1., 1.)), -90., 4).unwrap();
+    assert_closed(&mirrored);
+    let (d_min, d_max) = mirrored.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((0, -10, 0), (d_min.0, d_min.1, d_min.2));
+    assert_eq!((10, 0, 10), (d_max.0, d_max.1, d_max.2));
+}
+
+#[test]
+fn rotate_extrude_crossing_axis() {
+    let profile = rectangle(Point(-1., 0.), Point(1., 1.));
+    assert_eq!(
+        Some(ExtrudeError::CrossesAxis),
+        Csg::rotate_extrude(&profile, 360., 8).err()
+    );
+}
+
+#[test]
+fn sweep_bend() {
+    let profile = rectangle(Point(-0.5, -0.5), Point(0.5, 0.5));
+    let path = [Vector(0., 0., 0.), Vector(0., 0., 2.), Vector(2., 0., 2.)];
+    let solid = Csg::sweep(&profile, &path, SweepFrame::RotationMinimizing, true).unwrap();
+
+    assert_eq!(2 * 4 + 2 * 2, solid.polygons.len());
+    assert_closed(&solid);
+
+    // Mitered corner keeps the full width
+    let (d_min, d_max) = solid.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((-5, -5, 0), (d_min.0, d_min.1, d_min.2));
+    assert_eq!((20, 5, 25), (d_max.0, d_max.1, d_max.2));
+}
+
+#[test]
+fn sweep_uncapped() {
+    let profile = rectangle(Point(-0.5, -0.5), Point(0.5, 0.5));
+    let path = [Vector(0., 0., 0.), Vector(0., 0., 2.), Vector(2., 0., 2.)];
+    let tube = Csg::sweep(&profile, &path, SweepFrame::RotationMinimizing, false).unwrap();
+
+    // Only the walls, nothing covers the ends at z = 0 and x = 2
+    assert_eq!(2 * 4, tube.polygons.len());
+    for poly in &tube.polygons {
+        assert!(poly.vertices.iter().any(|v| v.position.2.abs() > 0.0001));
+        assert!(poly
+            .vertices
+            .iter()
+            .any(|v| (v.position.0 - 2.).abs() > 0.0001));
+    }
+}
+
+#[test]
+fn sweep_closed_path() {
+    let profile = circle(Point(0., 0.), 0.25, 8);
+    let path: Vec<Vector> = (0..13)
+        .map(|i| {
+            let angle = (i as f32) / 12. * 2. * ::UNIT_PI;
+            Vector(angle.cos() * 2., angle.sin() * 2., (angle * 3.).sin() * 0.5)
+        })
+        .collect();
+
+    let ring = Csg::sweep(&profile, &path, SweepFrame::RotationMinimizing, true).unwrap();
+
+    // Ends are joined without caps, which would break the closed check
+    assert!(ring.polygons.len() >= 12 * 8);
+    assert_closed(&ring);
+}
+
+/// Direction of the profile X axis where `solid`, swept from a small square in the first
+/// quadrant, passes `origin` with tangent `t`. The square is stretched across bends, so the
+/// rotation is taken from the polar decomposition of the placed axes.
+fn profile_x(solid: &Csg, origin: Vector, t: Vector) -> Vector {
+    let mut near: Vec<Vector> = Vec::new();
+    for v in solid.polygons.iter().flat_map(|p| p.vertices.iter()) {
+        let d = v.position - origin;
+        let in_plane = d.length() > 0.01 && d.length() < 0.5 && d.dot(t).abs() < 0.01;
+        if in_plane && near.iter().all(|n| (*n - d).length() > 0.001) {
+            near.push(d);
+        }
+    }
+
+    // The placed profile X and Y axes add up to the far corner of the square
+    assert_eq!(3, near.len());
+    let far = (0..3)
+        .find(|&i| (near[(i + 1) % 3] + near[(i + 2) % 3] - near[i]).length() < 0.001)
+        .unwrap();
+    let (mut ox, mut oy) = (near[(far + 1) % 3], near[(far + 2) % 3]);
+    if ox.cross(oy).dot(t) < 0. {
+        ::std::mem::swap(&mut ox, &mut oy);
+    }
+
+    let e1 = t.make_orthogonal().normalize();
+    let e2 = t.cross(e1);
+    let angle = (ox.dot(e2) - oy.dot(e1)).atan2(ox.dot(e1) + oy.dot(e2));
+    e1 * angle.cos() + e2 * angle.sin()
+}
+
+#[test]
+fn sweep_closed_path_twist() {
+    // Closed path without symmetry, so the frame comes back rotated after one lap
+    let path = [
+        Vector(0., 0., 0.),
+        Vector(3., 0., 0.),
+        Vector(3., 2., 1.),
+        Vector(1., 3., 2.),
+        Vector(0., 1., 3.),
+        Vector(0., 0., 0.),
+    ];
+    let profile = rectangle(Point(0., 0.), Point(0.1, 0.1));
+    let ring = Csg::sweep(&profile, &path, SweepFrame::RotationMinimizing, true).unwrap();
+    assert_closed(&ring);
+
+    let len = path.len() - 1;
+    let tangent = |i: usize| {
+        let (prev, next) = (path[(i + len - 1) % len], path[(i + 1) % len]);
+        ((path[i] - prev).normalize() + (next - path[i]).normalize()).normalize()
+    };
+    let xs: Vec<Vector> = (0..len)
+        .map(|i| profile_x(&ring, path[i], tangent(i)))
+        .collect();
+
+    // Twist around the tangent between neighbouring frames, the last one across the seam
+    let twists: Vec<f32> = (0..len)
+        .map(|i| {
+            let j = (i + 1) % len;
+            // Carry the frame along the segment by the double reflection method
+            let v1 = path[j] - path[i];
+            let reflect = |v: Vector, n: Vector| v - n * (2. * n.dot(v) / n.dot(n));
+            let v2 = tangent(j) - reflect(tangent(i), v1);
+            let carried = reflect(reflect(xs[i], v1), v2);
+            carried
+                .cross(xs[j])
+                .dot(tangent(j))
+                .atan2(carried.dot(xs[j]))
+                * 180.
+                / ::UNIT_PI
+        })
+        .collect();
+
+    // The rotation left after one lap is spread evenly, so the seam twists like every other
+    // step
+    assert!(twists[0].abs() > 0.1);
+    for twist in &twists {
+        assert!((twist - twists[0]).abs() < 0.01, "Twists {:?}", twists);
+    }
+}
+
+#[test]
+fn sweep_fixed_up() {
+    let profile = rectangle(Point(0., 0.), Point(0.2, 1.));
+    let path = [
+        Vector(0., 0., 0.),
+        Vector(1., 0., 0.),
+        Vector(2., 1., 0.),
+        Vector(3., 1., 0.),
+    ];
+
+    let rail = Csg::sweep(&profile, &path, SweepFrame::Fixed(Vector(0., 0., 1.)), true).unwrap();
+    assert_closed(&rail);
+
+    // Profile Y stays upright
+    let (d_min, d_max) = rail.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((0, 10), (d_min.2, d_max.2));
+
+    assert_eq!(
+        Some(ExtrudeError::PathTooShort),
+        Csg::sweep(&profile, &path[..1], SweepFrame::RotationMinimizing, true).err()
+    );
+}
+
+#[test]
+fn loft_rectangle_to_circle() {
+    let sections = vec![
+        (
+            rectangle(Point(-1., -1.), Point(1., 1.)),
+            Placement::at_height(0.),
+        ),
+        (circle(Point(0., 0.), 0.5, 16), Placement::at_height(2.)),
+    ];
+
+    let duct = Csg::loft(&sections).unwrap();
+    assert_closed(&duct);
+
+    let (d_min, d_max) = duct.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((-10, -10, 0), (d_min.0, d_min.1, d_min.2));
+    assert_eq!((10, 10, 20), (d_max.0, d_max.1, d_max.2));
+
+    // Bottom faces down, top faces up
+    assert!(duct
+        .polygons
+        .iter()
+        .filter(|p| p.vertices.iter().all(|v| v.position.2 == 0.))
+        .all(|p| p.plane.0 .2 < -0.99));
+    assert!(duct
+        .polygons
+        .iter()
+        .filter(|p| p.vertices.iter().all(|v| v.position.2 == 2.))
+        .all(|p| p.plane.0 .2 > 0.99));
+}
+
+#[test]
+fn loft_reversed_placement() {
+    // Stacked downwards, so the outlines would face inwards without correction
+    let flipped = |z| Placement::new(Vector(0., 0., z), Vector(0., 1., 0.), Vector(1., 0., 0.));
+    let sections = vec![
+        (rectangle(Point(-1., -1.), Point(1., 1.)), flipped(0.)),
+        (rectangle(Point(-1., -1.), Point(1., 1.)), flipped(1.)),
+        (circle(Point(0., 0.), 1., 8), flipped(2.)),
+    ];
+
+    let solid = Csg::loft(&sections).unwrap();
+    assert_closed(&solid);
+    assert!(solid
+        .polygons
+        .iter()
+        .filter(|p| p.vertices.iter().all(|v| v.position.2 == 0.))
+        .all(|p| p.plane.0 .2 < -0.99));
+}
+
+#[test]
+fn loft_bad_sections() {
+    let square = rectangle(Point(0., 0.), Point(1., 1.));
+    assert_eq!(
+        Some(ExtrudeError::TooFewSections),
+        Csg::loft(&[(square.clone(), Placement::at_height(0.))]).err()
+    );
+    assert_eq!(
+        Some(ExtrudeError::InvalidSection(1)),
+        Csg::loft(&[
+            (square, Placement::at_height(0.)),
+            (frame(), Placement::at_height(1.)),
+        ])
+        .err()
+    );
+}
@@ -0,0 +1,69 @@
+use dim3::{Csg, Vector};
+use UNIT_PI;
+
+fn assert_near(expected: f32, actual: f32, tolerance: f32) {
+    assert!(
+        (expected - actual).abs() <= tolerance * expected.abs().max(1.),
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn mass_cube() {
+    let (w, d, h) = (2., 3., 4.);
+    let cube = Csg::cube(Vector(w, d, h), false).translate(Vector(10., -5., 1.));
+
+    assert_near(24., cube.volume(), 0.0001);
+    assert_near(2. * (w * d + w * h + d * h), cube.surface_area(), 0.0001);
+
+    let c = cube.centroid();
+    assert_near(11., c.0, 0.0001);
+    assert_near(-3.5, c.1, 0.0001);
+    assert_near(3., c.2, 0.0001);
+
+    let density = 2.5;
+    let mass = 24. * density;
+    let tensor = cube.inertia_tensor(density);
+    assert_near(mass / 12. * (d * d + h * h), tensor[0][0], 0.001);
+    assert_near(mass / 12. * (w * w + h * h), tensor[1][1], 0.001);
+    assert_near(mass / 12. * (w * w + d * d), tensor[2][2], 0.001);
+    for &(i, j) in &[(0, 1), (0, 2), (1, 2)] {
+        assert!(tensor[i][j].abs() < 0.01);
+        assert_eq!(tensor[i][j], tensor[j][i]);
+    }
+
+    // Turned inside out, the volume is negative
+    assert_near(-24., cube.inverse().volume(), 0.0001);
+}
+
+#[test]
+fn mass_sphere() {
+    let r = 2.;
+    let sphere = Csg::sphere(r, 64, 32).translate(Vector(1., 2., 3.));
+
+    assert_near(4. / 3. * UNIT_PI * r * r * r, sphere.volume(), 0.01);
+    assert_near(4. * UNIT_PI * r * r, sphere.surface_area(), 0.01);
+
+    let c = sphere.centroid();
+    assert!((c - Vector(1., 2., 3.)).length() < 0.001);
+
+    let mass = sphere.volume();
+    let tensor = sphere.inertia_tensor(1.);
+    for (i, row) in tensor.iter().enumerate() {
+        assert_near(0.4 * mass * r * r, row[i], 0.01);
+    }
+
+    let ico = Csg::icosphere(r, 4);
+    assert_near(4. / 3. * UNIT_PI * r * r * r, ico.volume(), 0.01);
+}
+
+#[test]
+fn mass_empty() {
+    let empty = Csg::new();
+    assert_eq!(0., empty.volume());
+    assert_eq!(0., empty.surface_area());
+    assert_eq!(0., empty.centroid().length());
+    assert_eq!([[0.; 3]; 3], empty.inertia_tensor(1.));
+}
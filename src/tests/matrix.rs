@@ -0,0 +1,200 @@
+use dim3::{Csg, Matrix4, Placement, Vector};
+use tests::primitives::{assert_closed, assert_outward};
+
+fn assert_near(a: Vector, b: Vector) {
+    assert!((a - b).length() < 0.0001, "{:?} != {:?}", a, b);
+}
+
+fn assert_identity(m: &Matrix4) {
+    let id = Matrix4::identity();
+    for r in 0..4 {
+        for c in 0..4 {
+            assert!((m.0[r][c] - id.0[r][c]).abs() < 0.0001, "{:?}", m);
+        }
+    }
+}
+
+/// Vertices run counter clockwise around the plane normal.
+fn assert_winding(csg: &Csg) {
+    for poly in &csg.polygons {
+        let v = &poly.vertices;
+        let n = (v[1].position - v[0].position).cross(v[2].position - v[0].position);
+        assert!(n.dot(poly.plane.0) > 0.);
+    }
+}
+
+#[test]
+fn matrix_constructors() {
+    let p = Vector(1., 2., 3.);
+
+    assert_near(
+        Vector(2., 4., 6.),
+        Matrix4::translation(Vector(1., 2., 3.)).transform_point(p),
+    );
+    assert_near(p, Matrix4::translation(p).transform_vector(p));
+
+    // Clockwise like Csg::rotate
+    let r = Matrix4::rotation(Vector(0., 0., 2.), 90.);
+    assert_near(Vector(0., -1., 0.), r.transform_point(Vector(1., 0., 0.)));
+    assert_near(
+        Vector(0.3, -1., 2.).rotate(Vector(1., 2., -0.5), 75.),
+        Matrix4::rotation(Vector(1., 2., -0.5), 75.).transform_point(Vector(0.3, -1., 2.)),
+    );
+
+    let e = Matrix4::euler(90., 0., 90.);
+    assert_near(Vector(1., 0., 0.), e.transform_point(Vector(0., 0., 1.)));
+
+    assert_near(
+        Vector(1., -2., 3.),
+        Matrix4::mirror(Vector(0., 3., 0.)).transform_point(p),
+    );
+    assert_near(
+        Vector(1. + 2. * 0.5, 2., 3.),
+        Matrix4::shear(0.5, 0., 0., 0., 0., 0.).transform_point(p),
+    );
+
+    // The target ends up straight ahead of the camera
+    let view = Matrix4::look_at(Vector(5., 0., 0.), Vector(0., 0., 0.), Vector(0., 0., 1.));
+    assert_near(
+        Vector(0., 0., -5.),
+        view.transform_point(Vector(0., 0., 0.)),
+    );
+    assert_near(
+        Vector(0., 1., 0.),
+        view.transform_vector(Vector(0., 0., 1.)),
+    );
+
+    let placement = Placement::at_height(2.);
+    assert_near(
+        placement.place(::dim2::Point(3., 4.)),
+        placement.matrix().transform_point(Vector(3., 4., 0.)),
+    );
+}
+
+#[test]
+fn matrix_compose_and_invert() {
+    let m = Matrix4::translation(Vector(1., -2., 3.))
+        * Matrix4::rotation(Vector(1., 1., 0.), 33.)
+        * Matrix4::shear(0.2, 0., 0., 0.4, 0.1, 0.)
+        * Matrix4::scaling(Vector(2., 3., -1.));
+
+    // Applied right to left
+    let p = Vector(0.5, 0.25, -1.);
+    let step = Matrix4::translation(Vector(1., -2., 3.)).transform_point(
+        Matrix4::rotation(Vector(1., 1., 0.), 33.).transform_point(
+            Matrix4::shear(0.2, 0., 0., 0.4, 0.1, 0.)
+                .transform_point(Matrix4::scaling(Vector(2., 3., -1.)).transform_point(p)),
+        ),
+    );
+    assert_near(step, m.transform_point(p));
+
+    assert_identity(&(m * m.inverse().unwrap()));
+    assert_identity(&(m.inverse().unwrap() * m));
+    assert!(m.determinant() < 0.);
+    assert!((Matrix4::rotation(Vector(0., 1., 1.), 70.).determinant() - 1.).abs() < 0.0001);
+
+    assert!(Matrix4::scaling(Vector(1., 0., 1.)).inverse().is_none());
+}
+
+#[test]
+fn csg_transform() {
+    let sphere = Csg::icosphere(1., 2);
+    let m = Matrix4::translation(Vector(3., 0., 0.)) * Matrix4::scaling(Vector(1., 3., 1.));
+    let egg = sphere.clone().transform(&m);
+
+    assert_closed(&egg);
+    assert_outward(&egg, Vector(3., 0., 0.));
+    assert_winding(&egg);
+
+    // Smooth normals stay perpendicular to the surface of the ellipsoid
+    for poly in &egg.polygons {
+        for v in &poly.vertices {
+            let local = v.position - Vector(3., 0., 0.);
+            let expected = Vector(local.0, local.1 / 9., local.2).normalize();
+            assert_near(expected, v.normal);
+        }
+    }
+
+    // Rotation matrices turn the same way as Csg::rotate
+    let axis = Vector(1., 2., -0.5);
+    let cube = Csg::cube(Vector(1., 2., 3.), false);
+    let turned = cube.clone().transform(&Matrix4::rotation(axis, 75.));
+    for (pa, pb) in turned
+        .polygons
+        .iter()
+        .zip(cube.rotate(axis, 75.).polygons.iter())
+    {
+        for (va, vb) in pa.vertices.iter().zip(pb.vertices.iter()) {
+            assert_near(vb.position, va.position);
+            assert_near(vb.normal, va.normal);
+        }
+    }
+
+    // Mirroring keeps the solid right side out, so booleans still work
+    let mirrored =
+        Csg::cube(Vector(2., 2., 2.), false).transform(&Matrix4::mirror(Vector(1., 0., 0.)));
+    assert_outward(&mirrored, Vector(-1., 1., 1.));
+    assert_winding(&mirrored);
+
+    let cut = Csg::intersect(&mirrored, &Csg::cube(Vector(2., 2., 2.), true));
+    assert!(!cut.polygons.is_empty());
+    assert_outward(&cut, Vector(-0.5, 0.5, 0.5));
+    for poly in &cut.polygons {
+        for v in &poly.vertices {
+            let p = v.position;
+            assert!(p.0 >= -1.0001 && p.0 <= 0.0001);
+            assert!(p.1 >= -0.0001 && p.1 <= 1.0001);
+            assert!(p.2 >= -0.0001 && p.2 <= 1.0001);
+        }
+    }
+
+    assert!(sphere
+        .transform(&Matrix4::scaling(Vector(1., 1., 0.)))
+        .polygons
+        .is_empty());
+
+    // Tiny scales are not mistaken for flattening
+    let tiny = Csg::cube(Vector(1., 1., 1.), false).scale(Vector(1e-5, 1e-5, 1e-5));
+    assert_eq!(6, tiny.polygons.len());
+    assert!(Matrix4::scaling(Vector(1e-5, 1e-5, 1e-5))
+        .inverse()
+        .is_some());
+    assert!(Matrix4::scaling(Vector(1., 0., 1.)).inverse().is_none());
+}
+
+#[test]
+fn scale_normals() {
+    let stretched = Csg::sphere(1., 16, 8).scale(Vector(1., 3., 1.));
+    assert_outward(&stretched, Vector(0., 0., 0.));
+    assert_winding(&stretched);
+
+    for poly in &stretched.polygons {
+        for v in &poly.vertices {
+            let p = v.position;
+            assert_near(Vector(p.0, p.1 / 9., p.2).normalize(), v.normal);
+        }
+    }
+}
+
+#[test]
+fn scale_negative_and_mirror() {
+    let flipped = Csg::cube(Vector(1., 2., 3.), false).scale(Vector(-1., 1., 1.));
+    assert_closed(&flipped);
+    assert_outward(&flipped, Vector(-0.5, 1., 1.5));
+    assert_winding(&flipped);
+
+    let mirrored = Csg::icosphere(1., 1)
+        .translate(Vector(0., 0., 2.))
+        .mirror(Vector(0., 0., 1.));
+    assert_closed(&mirrored);
+    assert_outward(&mirrored, Vector(0., 0., -2.));
+    assert_winding(&mirrored);
+
+    // Booleans see the mirrored part as a solid
+    let overlap = Csg::intersect(&mirrored, &Csg::cube(Vector(4., 4., 4.), true));
+    assert!(!overlap.polygons.is_empty());
+    assert!(overlap
+        .polygons
+        .iter()
+        .all(|p| p.vertices.iter().all(|v| v.position.2 < -0.9999)));
+}
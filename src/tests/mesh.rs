@@ -0,0 +1,161 @@
+use dim3::{Csg, MeshNormals, Polygon, Vector, Vertex};
+
+#[test]
+fn mesh_cube_flat() {
+    let mesh = Csg::cube(Vector(2., 2., 2.), true).to_indexed_mesh(0.001, MeshNormals::Flat);
+
+    // Every side has its own four corners
+    assert_eq!(24, mesh.positions.len());
+    assert_eq!(24, mesh.normals.len());
+    assert_eq!(12, mesh.indices.len());
+
+    for triangle in &mesh.indices {
+        let (a, b, c) = (
+            mesh.positions[triangle[0]],
+            mesh.positions[triangle[1]],
+            mesh.positions[triangle[2]],
+        );
+        let face = (b - a).cross(c - a).normalize();
+        for &i in triangle {
+            assert!((mesh.normals[i] - face).length() < 0.0001);
+        }
+    }
+}
+
+#[test]
+fn mesh_cube_smooth() {
+    let mesh = Csg::cube(Vector(2., 2., 2.), true).to_indexed_mesh(0.001, MeshNormals::Smooth);
+
+    assert_eq!(8, mesh.positions.len());
+    assert_eq!(8, mesh.normals.len());
+    assert_eq!(12, mesh.indices.len());
+    assert!(mesh.indices.iter().all(|t| t.iter().all(|&i| i < 8)));
+
+    for (p, n) in mesh.positions.iter().zip(&mesh.normals) {
+        assert!((n.length() - 1.).abs() < 0.0001);
+        assert!(p.dot(*n) > 0.);
+    }
+}
+
+#[test]
+fn mesh_icosphere_smooth() {
+    let sphere = Csg::icosphere(2., 2);
+    let mesh = sphere.to_indexed_mesh(0.001, MeshNormals::Smooth);
+
+    // Closed surface: V - E + F = 2, with E = 3F / 2
+    assert_eq!(
+        2,
+        mesh.positions.len() as i32 - mesh.indices.len() as i32 / 2
+    );
+    assert_eq!(sphere.polygons.len(), mesh.indices.len());
+
+    for (p, n) in mesh.positions.iter().zip(&mesh.normals) {
+        assert!((p.normalize() - *n).length() < 0.05);
+    }
+}
+
+#[test]
+fn mesh_weld_tolerance() {
+    // Corners of the second cube are moved just past grid cell borders
+    let cubes = Csg::from_polygons(
+        Csg::cube(Vector(1., 1., 1.), false)
+            .to_polygons()
+            .into_iter()
+            .chain(
+                Csg::cube(Vector(1., 1., 1.), false)
+                    .translate(Vector(1.0006, 0., -0.0006))
+                    .to_polygons(),
+            )
+            .collect(),
+    );
+
+    let mesh = cubes.to_indexed_mesh(0.001, MeshNormals::Smooth);
+    assert_eq!(12, mesh.positions.len());
+    assert_eq!(24, mesh.indices.len());
+
+    let mesh = cubes.to_indexed_mesh(0.0001, MeshNormals::Smooth);
+    assert_eq!(16, mesh.positions.len());
+}
+
+#[test]
+fn mesh_empty() {
+    let mesh = Csg::new().to_indexed_mesh(0.001, MeshNormals::Flat);
+    assert!(mesh.positions.is_empty());
+    assert!(mesh.normals.is_empty());
+    assert!(mesh.indices.is_empty());
+}
+
+#[test]
+fn mesh_far_from_origo() {
+    let cube = Csg::cube(Vector(1., 1., 1.), true).translate(Vector(30000., 0., 0.));
+    let mesh = cube.to_indexed_mesh(0.00001, MeshNormals::Smooth);
+    assert_eq!(8, mesh.positions.len());
+    assert_eq!(12, mesh.indices.len());
+}
+
+#[test]
+fn mesh_flat_tiny_tolerance() {
+    // Two faces sharing an edge, with normals (0.6, 0.8, 0) and (0.8, 0.6, 0)
+    let triangle = |c: Vector| {
+        let corners = [Vector(0., 0., 0.), Vector(0., 0., 1.), c];
+        Polygon::new(
+            corners
+                .iter()
+                .map(|&p| Vertex::new(p, Vector(0., 0., 0.)))
+                .collect(),
+        )
+    };
+    let faces = Csg::from_polygons(vec![
+        triangle(Vector(0.8, -0.6, 0.)),
+        triangle(Vector(0.6, -0.8, 0.)),
+    ]);
+
+    // The faces keep their own normals however small the tolerance is
+    let mesh = faces.to_indexed_mesh(1e-30, MeshNormals::Flat);
+    assert_eq!(6, mesh.positions.len());
+    for (triangle, normal) in mesh
+        .indices
+        .iter()
+        .zip(&[Vector(0.6, 0.8, 0.), Vector(0.8, 0.6, 0.)])
+    {
+        for &i in triangle {
+            assert!((mesh.normals[i] - *normal).length() < 0.0001);
+        }
+    }
+}
+
+#[test]
+fn mesh_flat_coplanar() {
+    // A square split into two triangles, the second with its shared corners slightly moved
+    let triangle = |corners: [Vector; 3]| {
+        Polygon::new(
+            corners
+                .iter()
+                .map(|&p| Vertex::new(p, Vector(0., 0., 0.)))
+                .collect(),
+        )
+    };
+    let square = Csg::from_polygons(vec![
+        triangle([Vector(0., 0., 0.), Vector(1., 0., 0.), Vector(1., 1., 0.)]),
+        triangle([
+            Vector(0.0001, 0., 0.),
+            Vector(1., 1.0001, 0.),
+            Vector(0., 1., 0.),
+        ]),
+    ]);
+
+    // The triangles share the vertices along their common edge, at the welded positions
+    let mesh = square.to_indexed_mesh(0.001, MeshNormals::Flat);
+    assert_eq!(4, mesh.positions.len());
+    assert_eq!(2, mesh.indices.len());
+    assert_eq!(mesh.indices[0][0], mesh.indices[1][0]);
+    assert_eq!(mesh.indices[0][2], mesh.indices[1][1]);
+    assert_eq!(0., mesh.positions[mesh.indices[1][0]].0);
+    assert_eq!(1., mesh.positions[mesh.indices[1][1]].1);
+}
+
+#[test]
+#[should_panic]
+fn mesh_zero_tolerance() {
+    Csg::cube(Vector(1., 1., 1.), true).to_indexed_mesh(0., MeshNormals::Flat);
+}
@@ -1,8 +1,24 @@
 mod bounding_box;
+mod bvh;
+mod containment;
 mod dim2;
+mod distance;
+mod extrude;
+mod mass;
+mod matrix;
+mod mesh;
+mod obj;
+mod overlap;
 mod plane;
+mod polyhedron;
+mod primitives;
+mod projection;
+mod quaternion;
+mod raycast;
+mod section;
+mod slicer;
+mod stl;
 
-use self::bounding_box::BoundBox;
 use dim3::{BspNode, Csg, Plane, Polygon, Vector, Vertex};
 
 #[test]
@@ -23,7 +39,7 @@ fn types() {
 #[test]
 fn csg_cube() {
     let cube = Csg::cube(Vector(2., 2., 2.), true);
-    let bb = BoundBox::from_csg(&cube);
+    let bb = cube.bounding_box();
 
     // Get ivectors of bounding box, coords snapped to closest 0.1
     let (d_min, d_max) = bb.get_min_max_discreet(10.);
@@ -51,7 +67,7 @@ fn csg_total_subtraction() {
 #[test]
 fn csg_sphere() {
     let sphere = Csg::sphere(1.0, 10, 5);
-    let bb = BoundBox::from_csg(&sphere);
+    let bb = sphere.bounding_box();
 
     let (d_min, d_max) = bb.get_min_max_discreet(10.);
 
@@ -0,0 +1,66 @@
+use dim3::{Csg, ObjError, Vector};
+
+#[test]
+fn obj_cube_shared_vertices() {
+    let cube = Csg::cube(Vector(1., 1., 1.), false);
+    let mut buf = Vec::new();
+    cube.to_obj(&mut buf).unwrap();
+
+    let text = String::from_utf8(buf).unwrap();
+    assert_eq!(8, text.lines().filter(|l| l.starts_with("v ")).count());
+    assert_eq!(6, text.lines().filter(|l| l.starts_with("vn ")).count());
+
+    // Quads are kept as quads
+    let faces: Vec<&str> = text.lines().filter(|l| l.starts_with("f ")).collect();
+    assert_eq!(6, faces.len());
+    assert!(faces.iter().all(|f| f.split_whitespace().count() == 5));
+}
+
+#[test]
+fn obj_round_trip_normals() {
+    let sphere = Csg::sphere(1.0, 8, 4);
+    let mut buf = Vec::new();
+    sphere.to_obj(&mut buf).unwrap();
+
+    let read = Csg::from_obj(&buf[..]).unwrap();
+    assert_eq!(sphere.polygons.len(), read.polygons.len());
+
+    for (a, b) in sphere.polygons.iter().zip(read.polygons.iter()) {
+        assert_eq!(a.vertices.len(), b.vertices.len());
+        for (va, vb) in a.vertices.iter().zip(b.vertices.iter()) {
+            assert!((va.position - vb.position).length() < 0.0001);
+            assert!((va.normal - vb.normal).length() < 0.0001);
+        }
+    }
+}
+
+#[test]
+fn obj_round_trip_groups() {
+    let cube = Csg::cube(Vector(1., 1., 1.), false);
+    let sphere = Csg::sphere(1.0, 8, 4);
+    let mut buf = Vec::new();
+    Csg::to_obj_groups(&[("housing", &cube), ("knob", &sphere)], &mut buf).unwrap();
+
+    let groups = Csg::from_obj_groups(&buf[..]).unwrap();
+    assert_eq!(2, groups.len());
+    assert_eq!("housing", groups[0].0);
+    assert_eq!(cube.polygons.len(), groups[0].1.polygons.len());
+    assert_eq!("knob", groups[1].0);
+    assert_eq!(sphere.polygons.len(), groups[1].1.polygons.len());
+}
+
+#[test]
+fn obj_read_without_normals() {
+    let text = "o tri\nv 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\nf -3/1 -2/2 -1/3\n";
+    let groups = Csg::from_obj_groups(text.as_bytes()).unwrap();
+
+    assert_eq!(1, groups.len());
+    assert_eq!("tri", groups[0].0);
+    assert_eq!(2, groups[0].1.polygons.len());
+    assert!(groups[0].1.polygons[0].vertices[0].normal.2 > 0.9);
+
+    match Csg::from_obj("v 0 0 0\nf 1 2 3\n".as_bytes()) {
+        Err(ObjError::Parse(2, _)) => (),
+        _ => panic!("Expected out of range index"),
+    }
+}
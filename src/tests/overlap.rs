@@ -0,0 +1,69 @@
+use dim3::{Csg, Vector};
+
+fn unit_cube(at: Vector) -> Csg {
+    Csg::cube(Vector(1., 1., 1.), false).translate(at)
+}
+
+#[test]
+fn intersects_cubes() {
+    let a = unit_cube(Vector(0., 0., 0.));
+
+    assert!(Csg::intersects(&a, &unit_cube(Vector(0.5, 0.5, 0.5))));
+    assert!(!Csg::intersects(&a, &unit_cube(Vector(3., 0., 0.))));
+
+    // Boxes overlap, but the solids do not
+    let tilted = unit_cube(Vector(-0.5, -0.5, -0.5))
+        .rotate(Vector(0., 0., 1.), 45.)
+        .translate(Vector(1.5, 1.5, 0.5));
+    assert!(a.bounding_box().intersects(&tilted.bounding_box()));
+    assert!(!Csg::intersects(&a, &tilted));
+
+    // Touching faces and edges count
+    assert!(Csg::intersects(&a, &unit_cube(Vector(1., 0., 0.))));
+    assert!(Csg::intersects(&a, &unit_cube(Vector(1., 1., 0.))));
+
+    // One completely inside the other, without the surfaces meeting
+    let small = Csg::cube(Vector(0.2, 0.2, 0.2), true).translate(Vector(0.5, 0.5, 0.5));
+    assert!(Csg::intersects(&a, &small));
+    assert!(Csg::intersects(&small, &a));
+
+    assert!(!Csg::intersects(&a, &Csg::new()));
+}
+
+#[test]
+fn min_distance_solids() {
+    let a = unit_cube(Vector(0., 0., 0.));
+
+    let d = Csg::min_distance(&a, &unit_cube(Vector(3., 0., 0.)));
+    assert!((d - 2.).abs() < 0.0001);
+
+    // Corner to corner
+    let d = Csg::min_distance(&a, &unit_cube(Vector(2., 2., 2.)));
+    assert!((d - 3f32.sqrt()).abs() < 0.0001);
+
+    // Edge to edge, crossing like an X
+    let bar_a = Csg::cube(Vector(4., 0.2, 0.2), true);
+    let bar_b = Csg::cube(Vector(0.2, 4., 0.2), true).translate(Vector(0., 0., 1.));
+    let d = Csg::min_distance(&bar_a, &bar_b);
+    assert!((d - 0.8).abs() < 0.0001);
+
+    // An edge pointing at the middle of a face
+    let tilted = unit_cube(Vector(-0.5, -0.5, -0.5))
+        .rotate(Vector(0., 1., 0.), 45.)
+        .translate(Vector(0.5, 0.5, 3.));
+    let d = Csg::min_distance(&a, &tilted);
+    assert!((d - (2. - 0.5 * 2f32.sqrt())).abs() < 0.0001);
+
+    assert_eq!(0., Csg::min_distance(&a, &unit_cube(Vector(0.5, 0., 0.))));
+    assert_eq!(f32::MAX, Csg::min_distance(&a, &Csg::new()));
+}
+
+#[test]
+fn overlap_volume_report() {
+    let a = unit_cube(Vector(0., 0., 0.));
+
+    let v = Csg::overlap_volume(&a, &unit_cube(Vector(0.5, 0.5, 0.)));
+    assert!((v - 0.25).abs() < 0.0001);
+
+    assert_eq!(0., Csg::overlap_volume(&a, &unit_cube(Vector(2., 0., 0.))));
+}
@@ -0,0 +1,155 @@
+use super::primitives::{assert_closed, assert_outward};
+use dim3::{Csg, PolyhedronError, Vector};
+
+/// Prism with an L shaped profile, so the top and bottom faces are not convex.
+fn l_prism() -> (Vec<Vector>, Vec<Vec<usize>>) {
+    let profile = [(0., 0.), (2., 0.), (2., 1.), (1., 1.), (1., 2.), (0., 2.)];
+    let mut points: Vec<Vector> = profile.iter().map(|&(x, y)| Vector(x, y, 0.)).collect();
+    points.extend(profile.iter().map(|&(x, y)| Vector(x, y, 1.)));
+
+    let mut faces = vec![(0..6).rev().collect(), (6..12).collect()];
+    for i in 0..6 {
+        let j = (i + 1) % 6;
+        faces.push(vec![i, j, j + 6, i + 6]);
+    }
+
+    (points, faces)
+}
+
+#[test]
+fn polyhedron_cube() {
+    let points: Vec<Vector> = (0..8)
+        .map(|i| Vector((i & 1) as f32, ((i >> 1) & 1) as f32, ((i >> 2) & 1) as f32))
+        .collect();
+    let faces = vec![
+        vec![0, 4, 6, 2],
+        vec![1, 3, 7, 5],
+        vec![0, 1, 5, 4],
+        vec![2, 6, 7, 3],
+        vec![0, 2, 3, 1],
+        vec![4, 5, 7, 6],
+    ];
+
+    let cube = Csg::polyhedron(&points, &faces).unwrap();
+    assert_eq!(6, cube.polygons.len());
+    assert_closed(&cube);
+
+    // Subtracting the same cube built as a primitive leaves nothing
+    let rest = Csg::subtract(&cube, &Csg::cube(Vector(2., 2., 2.), false));
+    assert_eq!(0, rest.polygons.len());
+}
+
+#[test]
+fn polyhedron_openscad_order() {
+    // The cube example from the OpenSCAD manual, with clockwise faces
+    let points = vec![
+        Vector(0., 0., 0.),
+        Vector(10., 0., 0.),
+        Vector(10., 7., 0.),
+        Vector(0., 7., 0.),
+        Vector(0., 0., 5.),
+        Vector(10., 0., 5.),
+        Vector(10., 7., 5.),
+        Vector(0., 7., 5.),
+    ];
+    let faces = vec![
+        vec![0, 1, 2, 3],
+        vec![4, 5, 1, 0],
+        vec![7, 6, 5, 4],
+        vec![5, 6, 2, 1],
+        vec![6, 7, 3, 2],
+        vec![7, 4, 0, 3],
+    ];
+
+    let cube = Csg::polyhedron(&points, &faces).unwrap();
+    assert_closed(&cube);
+    assert_outward(&cube, Vector(5., 3.5, 2.5));
+    assert!((cube.volume() - 350.).abs() < 0.001);
+}
+
+#[test]
+fn polyhedron_non_convex_faces() {
+    let (points, faces) = l_prism();
+    let prism = Csg::polyhedron(&points, &faces).unwrap();
+
+    // Six side quads and the two L faces split into four triangles each
+    assert_eq!(6 + 2 * 4, prism.polygons.len());
+    assert_closed(&prism);
+
+    for poly in &prism.polygons {
+        for v in &poly.vertices {
+            assert!(v.normal.dot(poly.plane.0) > 0.99);
+        }
+    }
+}
+
+#[test]
+fn polyhedron_non_planar_face() {
+    // Tetrahedron-like shape with a twisted quad as base
+    let points = vec![
+        Vector(0., 0., 0.),
+        Vector(1., 0., 0.2),
+        Vector(1., 1., 0.),
+        Vector(0., 1., 0.2),
+        Vector(0.5, 0.5, 1.),
+    ];
+    let faces = vec![
+        vec![3, 2, 1, 0],
+        vec![0, 1, 4],
+        vec![1, 2, 4],
+        vec![2, 3, 4],
+        vec![3, 0, 4],
+    ];
+
+    let pyramid = Csg::polyhedron(&points, &faces).unwrap();
+    assert_eq!(2 + 4, pyramid.polygons.len());
+    assert_closed(&pyramid);
+}
+
+#[test]
+fn polyhedron_bad_input() {
+    let points = vec![
+        Vector(0., 0., 0.),
+        Vector(1., 0., 0.),
+        Vector(0., 1., 0.),
+        Vector(2., 0., 0.),
+    ];
+
+    assert_eq!(
+        Some(PolyhedronError::TooFewPoints(0)),
+        Csg::polyhedron(&points, &[vec![0, 1]]).err()
+    );
+    assert_eq!(
+        Some(PolyhedronError::IndexOutOfRange(1, 4)),
+        Csg::polyhedron(&points, &[vec![0, 1, 2], vec![0, 1, 4]]).err()
+    );
+    assert_eq!(
+        Some(PolyhedronError::DegenerateFace(0)),
+        Csg::polyhedron(&points, &[vec![0, 1, 3]]).err()
+    );
+    assert_eq!(
+        Some(PolyhedronError::UnmatchedEdge(0, 1)),
+        Csg::polyhedron(&points, &[vec![0, 1, 2]]).err()
+    );
+}
+
+#[test]
+fn polyhedron_mixed_winding() {
+    let points: Vec<Vector> = (0..8)
+        .map(|i| Vector((i & 1) as f32, ((i >> 1) & 1) as f32, ((i >> 2) & 1) as f32))
+        .collect();
+    let mut faces = vec![
+        vec![0, 4, 6, 2],
+        vec![1, 3, 7, 5],
+        vec![0, 1, 5, 4],
+        vec![2, 6, 7, 3],
+        vec![0, 2, 3, 1],
+        vec![4, 5, 7, 6],
+    ];
+    faces[3].reverse();
+
+    match Csg::polyhedron(&points, &faces) {
+        Err(PolyhedronError::UnmatchedEdge(_, _)) => (),
+        _ => panic!("Expected unmatched edge error"),
+    }
+}
@@ -0,0 +1,209 @@
+use dim3::{Csg, Vector};
+use std::collections::HashMap;
+
+type Key = (u32, u32, u32);
+
+fn key(v: Vector) -> Key {
+    // Snap away signed zeros and rounding noise so shared corners compare equal
+    let d = v.discreet(10000.);
+    (d.0 as u32, d.1 as u32, d.2 as u32)
+}
+
+/// Every edge must be matched by exactly one edge running the opposite direction.
+pub fn assert_closed(csg: &Csg) {
+    let mut edges: HashMap<(Key, Key), i32> = HashMap::new();
+
+    for poly in &csg.polygons {
+        let len = poly.vertices.len();
+        for i in 0..len {
+            let a = key(poly.vertices[i].position);
+            let b = key(poly.vertices[(i + 1) % len].position);
+            *edges.entry((a, b)).or_insert(0) += 1;
+        }
+    }
+
+    for (&(a, b), &count) in &edges {
+        assert_eq!(1, count, "Edge used {} times", count);
+        assert_eq!(Some(&1), edges.get(&(b, a)), "Edge has no twin");
+    }
+}
+
+/// For convex shapes, all polygons face away from a point inside.
+pub fn assert_outward(csg: &Csg, inside: Vector) {
+    for poly in &csg.polygons {
+        let mid = poly
+            .vertices
+            .iter()
+            .fold(Vector(0., 0., 0.), |acc, v| acc + v.position)
+            / (poly.vertices.len() as f32);
+
+        assert!(poly.plane.0.dot(mid - inside) > 0.);
+
+        for v in &poly.vertices {
+            assert!(v.normal.dot(poly.plane.0) > 0.);
+        }
+    }
+}
+
+#[test]
+fn cylinder() {
+    let cyl = Csg::cylinder(Vector(0., 0., -1.), Vector(0., 0., 1.), 0.5, 16);
+    assert_eq!(16 * 3, cyl.polygons.len());
+    assert_closed(&cyl);
+    assert_outward(&cyl, Vector(0., 0., 0.));
+
+    let (d_min, d_max) = cyl.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((-5, -5, -10), (d_min.0, d_min.1, d_min.2));
+    assert_eq!((5, 5, 10), (d_max.0, d_max.1, d_max.2));
+}
+
+#[test]
+fn cone() {
+    let frustum = Csg::cone(Vector(1., 0., 0.), Vector(3., 0., 0.), 1., 0.5, 12);
+    assert_eq!(12 * 3, frustum.polygons.len());
+    assert_closed(&frustum);
+    assert_outward(&frustum, Vector(2., 0., 0.));
+
+    let pointy = Csg::cone(Vector(0., 0., 0.), Vector(0., 2., 0.), 1., 0., 12);
+    assert_eq!(12 * 2, pointy.polygons.len());
+    assert_closed(&pointy);
+    assert_outward(&pointy, Vector(0., 0.5, 0.));
+}
+
+#[test]
+fn cone_degenerate() {
+    let (start, end) = (Vector(0., 0., 0.), Vector(0., 0., 2.));
+    assert!(Csg::cone(start, end, 0., 0., 12).polygons.is_empty());
+    assert!(Csg::cone(start, start, 1., 0.5, 12).polygons.is_empty());
+    assert!(Csg::cylinder(end, end, 1., 12).polygons.is_empty());
+    assert!(Csg::cylinder(start, end, -1., 12).polygons.is_empty());
+    assert!(Csg::cylinder(start, end, 1., 2).polygons.is_empty());
+    assert!(Csg::cone(start, end, 1., 0., 0).polygons.is_empty());
+    assert_closed(&Csg::cylinder(start, end, 1., 3));
+}
+
+#[test]
+fn cylinder_hole() {
+    let hole = Csg::cylinder(Vector(0., 0., -2.), Vector(0., 0., 2.), 0.25, 8);
+    let drilled = Csg::subtract(&Csg::cube(Vector(2., 2., 2.), true), &hole);
+
+    // Boolean output has T-junctions, so only check that the hole walls are there
+    assert!(drilled.polygons.len() > 6 + 8);
+    for poly in &drilled.polygons {
+        for v in &poly.vertices {
+            assert!(v.position.0.hypot(v.position.1) > 0.24);
+        }
+    }
+}
+
+#[test]
+fn torus() {
+    let torus = Csg::torus(2., 0.5, 16, 8);
+    assert_eq!(16 * 8, torus.polygons.len());
+    assert_closed(&torus);
+
+    let (d_min, d_max) = torus.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((-25, -25, -5), (d_min.0, d_min.1, d_min.2));
+    assert_eq!((25, 25, 5), (d_max.0, d_max.1, d_max.2));
+
+    // Tube normals point away from the ring through the tube centers
+    for poly in &torus.polygons {
+        for v in &poly.vertices {
+            let p = v.position;
+            let ring = Vector(p.0, p.1, 0.).normalize() * 2.;
+            assert!(v.normal.dot(p - ring) > 0.);
+        }
+    }
+}
+
+#[test]
+fn capsule() {
+    let capsule = Csg::capsule(Vector(0., 0., 0.), Vector(0., 0., 2.), 0.5, 12, 4);
+    assert_eq!(12 * (2 * 4 + 1), capsule.polygons.len());
+    assert_closed(&capsule);
+    assert_outward(&capsule, Vector(0., 0., 1.));
+
+    let (d_min, d_max) = capsule.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((-5, -5, -5), (d_min.0, d_min.1, d_min.2));
+    assert_eq!((5, 5, 25), (d_max.0, d_max.1, d_max.2));
+}
+
+#[test]
+fn capsule_degenerate() {
+    let (a, b) = (Vector(0., 0., 0.), Vector(0., 0., 2.));
+    assert!(Csg::capsule(a, b, 0.5, 12, 0).polygons.is_empty());
+    assert!(Csg::capsule(a, b, 0.5, 2, 4).polygons.is_empty());
+    assert!(Csg::capsule(a, b, 0., 12, 4).polygons.is_empty());
+    assert!(Csg::capsule(a, a, 0.5, 12, 4).polygons.is_empty());
+    assert_closed(&Csg::capsule(a, b, 0.5, 3, 1));
+}
+
+#[test]
+fn torus_degenerate() {
+    assert!(Csg::torus(2., 0.5, 2, 8).polygons.is_empty());
+    assert!(Csg::torus(2., 0.5, 16, 2).polygons.is_empty());
+    assert!(Csg::torus(2., 0., 16, 8).polygons.is_empty());
+    assert!(Csg::torus(2., 2., 16, 8).polygons.is_empty());
+    assert_closed(&Csg::torus(2., 0.5, 3, 3));
+}
+
+#[test]
+fn rounded_cube() {
+    let rounded = Csg::rounded_cube(Vector(2., 3., 4.), 0.5, 4, false);
+    assert_closed(&rounded);
+    assert_outward(&rounded, Vector(1., 1.5, 2.));
+
+    let (d_min, d_max) = rounded.bounding_box().get_min_max_discreet(10.);
+    assert_eq!((0, 0, 0), (d_min.0, d_min.1, d_min.2));
+    assert_eq!((20, 30, 40), (d_max.0, d_max.1, d_max.2));
+
+    // Radius larger than the cube degrades gracefully into a sphere-like shape
+    let clamped = Csg::rounded_cube(Vector(1., 1., 1.), 2., 3, true);
+    assert_closed(&clamped);
+}
+
+#[test]
+fn sphere() {
+    // Every band of the sphere, caps included, faces away from the center
+    for &(slices, stacks) in &[(16, 8), (5, 3), (32, 16)] {
+        assert_outward(&Csg::sphere(1., slices, stacks), Vector(0., 0., 0.));
+    }
+}
+
+#[test]
+fn icosphere() {
+    let sphere = Csg::icosphere(2., 2);
+    assert_eq!(20 * 16, sphere.polygons.len());
+    assert_closed(&sphere);
+    assert_outward(&sphere, Vector(0., 0., 0.));
+
+    for poly in &sphere.polygons {
+        for v in &poly.vertices {
+            assert!((v.position.length() - 2.).abs() < 0.0001);
+        }
+    }
+}
+
+#[test]
+fn platonic_solids() {
+    let solids = [
+        (Csg::tetrahedron(1.), 4, 3),
+        (Csg::octahedron(1.), 8, 3),
+        (Csg::icosahedron(1.), 20, 3),
+        (Csg::dodecahedron(1.), 12, 5),
+    ];
+
+    for &(ref solid, faces, corners) in solids.iter() {
+        assert_eq!(faces, solid.polygons.len());
+        assert!(solid.polygons.iter().all(|p| p.vertices.len() == corners));
+        assert_closed(solid);
+        assert_outward(solid, Vector(0., 0., 0.));
+
+        for poly in &solid.polygons {
+            for v in &poly.vertices {
+                assert!((v.position.length() - 1.).abs() < 0.0001);
+                assert!((poly.plane.0.dot(v.position) - poly.plane.1).abs() < 0.0001);
+            }
+        }
+    }
+}
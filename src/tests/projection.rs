@@ -0,0 +1,38 @@
+use dim2::signed_area;
+use dim3::{Csg, Vector};
+
+#[test]
+fn project_cube() {
+    let cube = Csg::cube(Vector(2., 3., 4.), false).rotate(Vector(0., 0., 1.), 30.);
+    let shadow = cube.project_xy();
+
+    assert_eq!(4, shadow.lines.len());
+    let loops = shadow.to_loops();
+    assert_eq!(1, loops.len());
+    assert!((signed_area(&loops[0]) + 2. * 6.).abs() < 0.001);
+}
+
+#[test]
+fn project_sphere() {
+    let shadow = Csg::icosphere(1., 2).project_xy();
+    let loops = shadow.to_loops();
+
+    assert_eq!(1, loops.len());
+    for p in &loops[0] {
+        assert!(p.length() > 0.9 && p.length() < 1.0001);
+    }
+}
+
+#[test]
+fn project_tube_and_block() {
+    let tube = Csg::subtract(
+        &Csg::cylinder(Vector(0., 0., 0.), Vector(0., 0., 2.), 2., 16),
+        &Csg::cylinder(Vector(0., 0., -1.), Vector(0., 0., 3.), 1., 16),
+    );
+    let block = Csg::cube(Vector(1., 1., 1.), false).translate(Vector(5., 0., 0.));
+
+    let regions = Csg::union(&tube, &block).project_xy().to_regions();
+    assert_eq!(2, regions.len());
+    assert_eq!(1, regions[0].holes.len());
+    assert!(regions[1].holes.is_empty());
+}
@@ -0,0 +1,93 @@
+use dim3::{Csg, Matrix4, Quaternion, Vector};
+
+fn assert_near(a: Vector, b: Vector) {
+    assert!((a - b).length() < 0.0001, "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn quaternion_axis_angle() {
+    let axis = Vector(1., 2., -0.5);
+    let q = Quaternion::from_axis_angle(axis, 75.);
+    let m = Matrix4::rotation(axis, 75.);
+    assert!((q.length() - 1.).abs() < 0.0001);
+
+    let p = Vector(0.3, -1., 2.);
+    assert_near(m.transform_point(p), q.rotate_vector(p));
+    assert_near(m.transform_point(p), q.to_matrix().transform_point(p));
+    assert_near(p, (q.conjugate() * q).rotate_vector(p));
+
+    // Composition applies the right hand side first
+    let r = Quaternion::from_axis_angle(Vector(0., 0., 1.), 90.);
+    assert_near(
+        r.rotate_vector(q.rotate_vector(p)),
+        (r * q).rotate_vector(p),
+    );
+}
+
+#[test]
+fn quaternion_two_vectors() {
+    let pairs = [
+        (Vector(1., 0., 0.), Vector(0., 1., 0.)),
+        (Vector(1., 2., 3.), Vector(-2., 0.5, 1.)),
+        (Vector(0., 0., 2.), Vector(0., 0., 1.)),
+        (Vector(0., 1., 1.), Vector(0., -1., -1.)),
+    ];
+
+    for &(from, to) in &pairs {
+        let q = Quaternion::from_two_vectors(from, to);
+        assert!((q.length() - 1.).abs() < 0.0001);
+        assert_near(to.normalize(), q.rotate_vector(from.normalize()));
+    }
+}
+
+#[test]
+fn quaternion_slerp() {
+    let axis = Vector(0., 1., 0.);
+    let a = Quaternion::from_axis_angle(axis, 10.);
+    let b = Quaternion::from_axis_angle(axis, 130.);
+    let p = Vector(1., 0., 0.);
+
+    assert_near(a.rotate_vector(p), a.slerp(b, 0.).rotate_vector(p));
+    assert_near(b.rotate_vector(p), a.slerp(b, 1.).rotate_vector(p));
+    assert_near(
+        Quaternion::from_axis_angle(axis, 40.).rotate_vector(p),
+        a.slerp(b, 0.25).rotate_vector(p),
+    );
+
+    // The same rotation with opposite sign still takes the short way
+    let c = Quaternion::from_axis_angle(axis, 50.);
+    let negated = Quaternion::new(-c.w, -c.x, -c.y, -c.z);
+    assert_near(
+        Quaternion::from_axis_angle(axis, 30.).rotate_vector(p),
+        a.slerp(negated, 0.5).rotate_vector(p),
+    );
+
+    // Nearly equal rotations do not divide by zero
+    let q = a.slerp(a, 0.5);
+    assert_near(a.rotate_vector(p), q.rotate_vector(p));
+}
+
+#[test]
+fn csg_rotate_quat() {
+    let q = Quaternion::from_axis_angle(Vector(1., 1., 0.), 35.);
+    let cube = Csg::cube(Vector(1., 2., 3.), false);
+    let a = cube.clone().rotate_quat(q);
+    let b = cube.transform(&Matrix4::rotation(Vector(1., 1., 0.), 35.));
+
+    for (pa, pb) in a.polygons.iter().zip(b.polygons.iter()) {
+        assert_near(pa.plane.0, pb.plane.0);
+        for (va, vb) in pa.vertices.iter().zip(pb.vertices.iter()) {
+            assert_near(va.position, vb.position);
+            assert_near(va.normal, vb.normal);
+        }
+    }
+}
+
+#[test]
+fn vector_rotate_unnormalized_axis() {
+    let v = Vector(1., 2., 3.);
+    assert_near(
+        v.rotate(Vector(0., 0., 1.), 30.),
+        v.rotate(Vector(0., 0., 5.), 30.),
+    );
+}
@@ -0,0 +1,116 @@
+use dim3::{Bvh, Csg, Vector};
+
+fn assert_near(a: Vector, b: Vector) {
+    assert!((a - b).length() < 0.0001, "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn raycast_cube() {
+    let cube = Csg::cube(Vector(2., 2., 2.), true);
+
+    let hit = cube
+        .raycast(Vector(-5., 0.2, 0.3), Vector(2., 0., 0.))
+        .unwrap();
+    assert!((hit.t - 2.).abs() < 0.0001);
+    assert_near(Vector(-1., 0.2, 0.3), hit.point);
+    assert_near(Vector(-1., 0., 0.), hit.normal);
+    assert_near(
+        Vector(-1., 0., 0.),
+        cube.polygons[hit.polygon_index].plane.0,
+    );
+
+    // Misses and rays pointing away
+    assert!(cube
+        .raycast(Vector(-5., 3., 0.), Vector(1., 0., 0.))
+        .is_none());
+    assert!(cube
+        .raycast(Vector(-5., 0., 0.), Vector(-1., 0., 0.))
+        .is_none());
+
+    // From the inside, the ray leaves through the far side
+    let hit = cube
+        .raycast(Vector(0., 0., 0.), Vector(0., 0., 1.))
+        .unwrap();
+    assert!((hit.t - 1.).abs() < 0.0001);
+    assert!(hit.normal.dot(Vector(0., 0., 1.)) > 0.);
+}
+
+#[test]
+fn raycast_all_crossings() {
+    let cube = Csg::cube(Vector(2., 2., 2.), true);
+    let hits = cube.raycast_all(Vector(0.5, 0.5, -3.), Vector(0., 0., 1.));
+    assert_eq!(2, hits.len());
+    assert!((hits[0].t - 2.).abs() < 0.0001);
+    assert!((hits[1].t - 4.).abs() < 0.0001);
+
+    // Through the edge between two faces, counted once on each side
+    let diagonal = cube.raycast_all(Vector(-3., -3., 0.), Vector(1., 1., 0.));
+    assert_eq!(2, diagonal.len());
+
+    // A tube is crossed four times through its middle
+    let tube = Csg::subtract(
+        &Csg::cylinder(Vector(0., 0., 0.), Vector(0., 0., 2.), 2., 16),
+        &Csg::cylinder(Vector(0., 0., -1.), Vector(0., 0., 3.), 1., 16),
+    );
+    let hits = tube.raycast_all(Vector(-5., 0.05, 1.), Vector(1., 0., 0.));
+    assert_eq!(4, hits.len());
+    let wall = hits[1].t - hits[0].t;
+    assert!(wall > 0.95 && wall < 1.05, "wall thickness {}", wall);
+}
+
+#[test]
+fn raycast_bvh_reused() {
+    let sphere = Csg::icosphere(1., 3);
+    let bvh = Bvh::new(&sphere);
+    assert_eq!(sphere.polygons.len(), bvh.polygons().len());
+
+    for i in 0..50 {
+        let angle = i as f32 * 0.37;
+        let origin = Vector(angle.cos() * 3., angle.sin() * 3., (i as f32 * 0.1) - 2.5);
+        let dir = (Vector(0., 0., 0.) - origin) + Vector(0.1, -0.05, 0.2);
+
+        let hits = bvh.raycast_all(origin, dir);
+        assert_eq!(2, hits.len());
+        for hit in &hits {
+            let r = hit.point.length();
+            assert!(r > 0.98 && r < 1.0001);
+            assert_near(hit.normal, bvh.polygons()[hit.polygon_index].plane.0);
+        }
+
+        let first = bvh.raycast(origin, dir).unwrap();
+        assert!((first.t - hits[0].t).abs() < 0.0001);
+        assert!(first.normal.dot(dir) < 0.);
+    }
+
+    assert!(Bvh::new(&Csg::new())
+        .raycast(Vector(0., 0., 0.), Vector(1., 0., 0.))
+        .is_none());
+}
+
+#[test]
+fn raycast_axis_aligned() {
+    // Rays along an axis, lying exactly in the plane of box sides and through corners
+    let sphere = Csg::icosphere(1., 1);
+    let hit = sphere
+        .raycast(Vector(0., 0., -5.), Vector(0., 0., 1.))
+        .unwrap();
+    assert!(hit.t > 3.9 && hit.t < 4.0001);
+
+    let cube = Csg::cube(Vector(1., 1., 1.), false);
+    let hit = cube
+        .raycast(Vector(0., 0.5, -5.), Vector(0., 0., 1.))
+        .unwrap();
+    assert!((hit.t - 5.).abs() < 0.0001);
+    let hit = cube
+        .raycast(Vector(0.5, 0.5, 5.), Vector(0., 0., -1.))
+        .unwrap();
+    assert!((hit.t - 4.).abs() < 0.0001);
+
+    let torus = Csg::torus(2., 0.5, 16, 8);
+    assert_eq!(
+        4,
+        torus
+            .raycast_all(Vector(-5., 0., 0.), Vector(1., 0., 0.))
+            .len()
+    );
+}
@@ -0,0 +1,92 @@
+use dim2::signed_area;
+use dim3::{Csg, Placement, Plane, Vector};
+
+fn horizontal(z: f32) -> Plane {
+    Plane(Vector(0., 0., 1.), z)
+}
+
+fn perimeter(points: &[::dim2::Point]) -> f32 {
+    let len = points.len();
+    (0..len)
+        .map(|i| (points[(i + 1) % len] - points[i]).length())
+        .sum()
+}
+
+#[test]
+fn section_cube() {
+    let cube = Csg::cube(Vector(2., 2., 2.), true);
+    let cut = cube.section(&horizontal(0.5));
+
+    assert_eq!(4, cut.lines.len());
+    let loops = cut.to_loops();
+    assert_eq!(1, loops.len());
+    assert!((perimeter(&loops[0]) - 8.).abs() < 0.0001);
+
+    // Outer outline runs clockwise
+    assert!((signed_area(&loops[0]) + 2. * 4.).abs() < 0.0001);
+
+    // Mapped back, the outline lies on the plane around the cube
+    let frame = Placement::from_plane(&horizontal(0.5));
+    for p in &loops[0] {
+        let v = frame.place(*p);
+        assert!((v.2 - 0.5).abs() < 0.0001);
+        assert!((v.0.abs() - 1.).abs() < 0.0001);
+        assert!((v.1.abs() - 1.).abs() < 0.0001);
+    }
+}
+
+#[test]
+fn section_through_face() {
+    // Cutting exactly at the top face still gives one outline
+    let cube = Csg::cube(Vector(2., 2., 2.), true);
+    let loops = cube.section(&horizontal(1.)).to_loops();
+    assert_eq!(1, loops.len());
+    assert!((perimeter(&loops[0]) - 8.).abs() < 0.0001);
+
+    assert!(cube.section(&horizontal(1.5)).lines.is_empty());
+}
+
+#[test]
+fn section_with_hole() {
+    let tube = Csg::subtract(
+        &Csg::cylinder(Vector(0., 0., 0.), Vector(0., 0., 2.), 2., 16),
+        &Csg::cylinder(Vector(0., 0., -1.), Vector(0., 0., 3.), 1., 16),
+    );
+
+    let cut = tube.section(&horizontal(1.));
+
+    // Clockwise outer wall, counter clockwise hole
+    let mut areas: Vec<f32> = cut.to_loops().iter().map(|l| signed_area(l)).collect();
+    areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(2, areas.len());
+    assert!(areas[0] < 0. && areas[1] > 0.);
+    assert!(-areas[0] > areas[1]);
+
+    let regions = cut.to_regions();
+    assert_eq!(1, regions.len());
+    assert_eq!(16, regions[0].outer.len());
+    assert_eq!(1, regions[0].holes.len());
+    assert_eq!(16, regions[0].holes[0].len());
+}
+
+#[test]
+fn section_sphere_tilted() {
+    let sphere = Csg::icosphere(1., 3);
+    let plane = Plane(Vector(1., 1., 0.).normalize(), 0.);
+    let loops = sphere.section(&plane).to_loops();
+
+    assert_eq!(1, loops.len());
+    for p in &loops[0] {
+        assert!(p.length() > 0.95 && p.length() < 1.0001);
+    }
+}
+
+#[test]
+fn section_shared_edges_close() {
+    // Neighbouring polygons must cut their shared edge at exactly the same point
+    let sphere = Csg::sphere(5., 32, 16);
+    for i in 1..50 {
+        let z = i as f32 * 0.2 - 4.9;
+        assert_eq!(1, sphere.section(&horizontal(z)).to_loops().len());
+    }
+}
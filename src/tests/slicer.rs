@@ -0,0 +1,158 @@
+use dim2::{signed_area, Point};
+use dim3::{Csg, Vector};
+use slicer::{offset_loop, slice, write_gcode, SlicerConfig};
+
+/// What a printer would do with the G-code, as far as the tests care.
+struct Printed {
+    layers: usize,
+    layer_heights: Vec<f32>,
+    min: Point,
+    max: Point,
+    /// Every point extruded to, with the height it was printed at.
+    extruded: Vec<(Point, f32)>,
+    filament: f32,
+}
+
+fn parse_gcode(text: &str) -> Printed {
+    let mut printed = Printed {
+        layers: 0,
+        layer_heights: Vec::new(),
+        min: Point(f32::MAX, f32::MAX),
+        max: Point(f32::MIN, f32::MIN),
+        extruded: Vec::new(),
+        filament: 0.,
+    };
+    let (mut x, mut y, mut z) = (0., 0., 0.);
+
+    for line in text.lines() {
+        if line.starts_with(";LAYER:") {
+            printed.layers += 1;
+            continue;
+        }
+
+        let code = line.split(';').next().unwrap().trim();
+        let mut words = code.split_whitespace();
+        let command = match words.next() {
+            Some(command) => command,
+            None => continue,
+        };
+        if command != "G0" && command != "G1" {
+            continue;
+        }
+
+        let mut e = None;
+        for word in words {
+            let value: f32 = word[1..].parse().unwrap();
+            match &word[..1] {
+                "X" => x = value,
+                "Y" => y = value,
+                "Z" => {
+                    z = value;
+                    printed.layer_heights.push(z);
+                }
+                "E" => e = Some(value),
+                _ => {}
+            }
+        }
+
+        if let Some(e) = e {
+            assert!(e >= printed.filament, "extrusion runs backwards");
+            printed.filament = e;
+            printed.min = Point(printed.min.0.min(x), printed.min.1.min(y));
+            printed.max = Point(printed.max.0.max(x), printed.max.1.max(y));
+            printed.extruded.push((Point(x, y), z));
+        }
+    }
+
+    printed
+}
+
+fn print(csg: &Csg, config: &SlicerConfig) -> Printed {
+    let mut out = Vec::new();
+    write_gcode(&slice(csg, config), config, &mut out).unwrap();
+    parse_gcode(&String::from_utf8(out).unwrap())
+}
+
+#[test]
+fn slice_cube() {
+    let config = SlicerConfig::default();
+    let cube = Csg::cube(Vector(10., 20., 4.), false).translate(Vector(5., 5., 3.));
+    let printed = print(&cube, &config);
+
+    assert_eq!(20, printed.layers);
+    assert_eq!(20, printed.layer_heights.len());
+    assert!((printed.layer_heights[0] - 0.2).abs() < 0.001);
+    assert!((printed.layer_heights[19] - 4.).abs() < 0.001);
+
+    // The outer perimeter is half a line width inside of the cube
+    let half = config.extrusion_width * 0.5;
+    assert!((printed.min.0 - (5. + half)).abs() < 0.001);
+    assert!((printed.min.1 - (5. + half)).abs() < 0.001);
+    assert!((printed.max.0 - (15. - half)).abs() < 0.001);
+    assert!((printed.max.1 - (25. - half)).abs() < 0.001);
+
+    // Two perimeters per layer, with infill inside of them
+    let layer = &slice(&cube, &config)[0];
+    assert_eq!(2, layer.perimeters.len());
+    assert!(!layer.infill.is_empty());
+    assert!(printed.filament > 0.);
+}
+
+#[test]
+fn slice_tube_keeps_hole() {
+    let config = SlicerConfig::default();
+    let tube = Csg::subtract(
+        &Csg::cylinder(Vector(0., 0., 0.), Vector(0., 0., 2.), 5., 32),
+        &Csg::cylinder(Vector(0., 0., -1.), Vector(0., 0., 3.), 2., 32),
+    );
+    let printed = print(&tube, &config);
+    assert_eq!(10, printed.layers);
+
+    // Nothing is printed in the hole or outside of the tube
+    let half = config.extrusion_width * 0.5;
+    for &(p, _) in &printed.extruded {
+        assert!(p.length() > 2. + half - 0.01);
+        assert!(p.length() < 5. - half + 0.01);
+    }
+
+    // Outline and hole each get two perimeters
+    assert_eq!(4, slice(&tube, &config)[0].perimeters.len());
+}
+
+#[test]
+fn slice_follows_shape() {
+    let config = SlicerConfig {
+        layer_height: 0.5,
+        ..SlicerConfig::default()
+    };
+    let sphere = Csg::sphere(5., 32, 16);
+    let layers = slice(&sphere, &config);
+    assert_eq!(20, layers.len());
+
+    // Layers near the equator are wider than those at the poles
+    let width = |i: usize| {
+        layers[i].perimeters[0]
+            .iter()
+            .map(|p| p.length())
+            .fold(0., f32::max)
+    };
+    assert!(width(10) > width(1));
+    assert!(width(10) > width(18));
+}
+
+#[test]
+fn offset_square() {
+    let square = vec![Point(0., 0.), Point(2., 0.), Point(2., 2.), Point(0., 2.)];
+
+    let inner = offset_loop(&square, 0.5).unwrap();
+    // The signed area is doubled
+    assert!((signed_area(&inner) - 2.).abs() < 0.0001);
+    assert!((inner[0].0 - 0.5).abs() < 0.0001 && (inner[0].1 - 0.5).abs() < 0.0001);
+
+    let outer = offset_loop(&square, -0.5).unwrap();
+    assert!((signed_area(&outer) - 18.).abs() < 0.0001);
+
+    // Shrinking by half the width or more leaves nothing
+    assert!(offset_loop(&square, 1.).is_none());
+    assert!(offset_loop(&square, 1.5).is_none());
+}
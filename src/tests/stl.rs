@@ -0,0 +1,212 @@
+use dim3::{Csg, StlError, Vector};
+
+fn read_u32_le(bytes: &[u8]) -> u32 {
+    (bytes[0] as u32)
+        | ((bytes[1] as u32) << 8)
+        | ((bytes[2] as u32) << 16)
+        | ((bytes[3] as u32) << 24)
+}
+
+fn read_f32_le(bytes: &[u8]) -> f32 {
+    f32::from_bits(read_u32_le(bytes))
+}
+
+fn read_vector_le(bytes: &[u8]) -> Vector {
+    Vector(
+        read_f32_le(&bytes[0..4]),
+        read_f32_le(&bytes[4..8]),
+        read_f32_le(&bytes[8..12]),
+    )
+}
+
+/// Normal and corners of every facet in a binary STL file.
+fn parse_binary(buf: &[u8]) -> Vec<(Vector, [Vector; 3])> {
+    buf[84..]
+        .chunks(50)
+        .map(|facet| {
+            (
+                read_vector_le(&facet[0..12]),
+                [
+                    read_vector_le(&facet[12..24]),
+                    read_vector_le(&facet[24..36]),
+                    read_vector_le(&facet[36..48]),
+                ],
+            )
+        })
+        .collect()
+}
+
+/// Normal and corners of every facet in an ASCII STL file.
+fn parse_ascii(text: &str) -> Vec<(Vector, [Vector; 3])> {
+    let vector = |line: &str, keyword: &str| {
+        let v: Vec<f32> = line
+            .trim()
+            .trim_start_matches(keyword)
+            .split_whitespace()
+            .map(|x| x.parse().unwrap())
+            .collect();
+        Vector(v[0], v[1], v[2])
+    };
+
+    let mut facets = Vec::new();
+    let mut lines = text.lines();
+    while let Some(line) = lines.next() {
+        if line.trim().starts_with("facet normal") {
+            let normal = vector(line, "facet normal");
+            lines.next();
+            let mut corners = [Vector(0., 0., 0.); 3];
+            for c in corners.iter_mut() {
+                *c = vector(lines.next().unwrap(), "vertex");
+            }
+            facets.push((normal, corners));
+        }
+    }
+    facets
+}
+
+/// The facets must be the triangles of `csg`, in the same order.
+fn assert_facets(csg: &Csg, facets: &[(Vector, [Vector; 3])], tolerance: f32) {
+    let triangles = csg.get_triangles();
+    assert_eq!(triangles.len(), facets.len());
+
+    for (tri, &(normal, corners)) in triangles.iter().zip(facets) {
+        assert!((tri.normal - normal).length() <= tolerance);
+        for (&a, &b) in tri.positions.iter().zip(corners.iter()) {
+            assert!((a - b).length() <= tolerance);
+        }
+    }
+}
+
+#[test]
+fn stl_binary_triangle_count() {
+    let sphere = Csg::sphere(1.0, 10, 5);
+    let mut buf = Vec::new();
+    sphere.to_stl_binary(&mut buf).unwrap();
+
+    let count = sphere.get_triangles_count();
+    assert_eq!(count as u32, read_u32_le(&buf[80..84]));
+    assert_eq!(84 + 50 * count, buf.len());
+    assert_facets(&sphere, &parse_binary(&buf), 0.);
+}
+
+#[test]
+fn stl_ascii_triangle_count() {
+    let cube = Csg::cube(Vector(1., 2., 3.), true);
+    let mut buf = Vec::new();
+    cube.to_stl_ascii("cube", &mut buf).unwrap();
+
+    let text = String::from_utf8(buf).unwrap();
+    assert!(text.starts_with("solid cube\n"));
+    assert!(text.trim_end().ends_with("endsolid cube"));
+    assert_eq!(
+        cube.get_triangles_count(),
+        text.matches("facet normal").count()
+    );
+    assert_eq!(
+        3 * cube.get_triangles_count(),
+        text.matches("vertex").count()
+    );
+    assert_facets(&cube, &parse_ascii(&text), 0.);
+}
+
+#[test]
+fn stl_binary_round_trip() {
+    let sphere = Csg::sphere(1.0, 10, 5);
+    let mut buf = Vec::new();
+    sphere.to_stl_binary(&mut buf).unwrap();
+
+    let read = Csg::from_stl(&buf[..]).unwrap();
+    assert_eq!(sphere.get_triangles_count(), read.get_triangles_count());
+    assert_facets(&read, &parse_binary(&buf), 0.0001);
+}
+
+#[test]
+fn stl_ascii_round_trip() {
+    let cube = Csg::cube(Vector(1., 2., 3.), true);
+    let mut buf = Vec::new();
+    cube.to_stl_ascii("cube", &mut buf).unwrap();
+
+    let read = Csg::from_stl(&buf[..]).unwrap();
+    assert_eq!(cube.get_triangles_count(), read.get_triangles_count());
+    assert_facets(
+        &read,
+        &parse_ascii(&String::from_utf8(buf).unwrap()),
+        0.0001,
+    );
+
+    // Welded corners are shared exactly between facets
+    let mut corners: Vec<(u32, u32, u32)> = read
+        .get_triangles()
+        .iter()
+        .flat_map(|t| t.positions.to_vec())
+        .map(|p| (p.0.to_bits(), p.1.to_bits(), p.2.to_bits()))
+        .collect();
+    corners.sort();
+    corners.dedup();
+    assert_eq!(8, corners.len());
+}
+
+const DEGENERATE_STL: &str = "solid bad
+  facet normal 0 0 1
+    outer loop
+      vertex 0 0 0
+      vertex 1 0 0
+      vertex 0 1 0
+    endloop
+  endfacet
+  facet normal 0 0 0
+    outer loop
+      vertex 0 0 0
+      vertex 1 0 0
+      vertex 2 0 0
+    endloop
+  endfacet
+endsolid bad
+";
+
+#[test]
+fn stl_degenerate_facet() {
+    match Csg::from_stl(DEGENERATE_STL.as_bytes()) {
+        Err(StlError::DegenerateFacet(1)) => (),
+        _ => panic!("Expected degenerate facet error"),
+    }
+
+    let (csg, skipped) = Csg::from_stl_lenient(DEGENERATE_STL.as_bytes()).unwrap();
+    assert_eq!(1, csg.polygons.len());
+    assert_eq!(vec![1], skipped);
+}
+
+#[test]
+fn stl_truncated_binary() {
+    let mut buf = Vec::new();
+    Csg::cube(Vector(1., 1., 1.), false)
+        .to_stl_binary(&mut buf)
+        .unwrap();
+    buf.truncate(200);
+
+    match Csg::from_stl(&buf[..]) {
+        Err(StlError::Truncated) => (),
+        _ => panic!("Expected truncated error"),
+    }
+}
+
+#[test]
+fn stl_far_from_origo() {
+    // Far enough out for the welding grid to need more than 32 bits
+    let cube = Csg::cube(Vector(1., 1., 1.), false).translate(Vector(100000., -50000., 30000.));
+    let mut buf = Vec::new();
+    cube.to_stl_binary(&mut buf).unwrap();
+
+    let read = Csg::from_stl(&buf[..]).unwrap();
+    assert_facets(&read, &parse_binary(&buf), 0.0001);
+
+    let mut corners: Vec<(u32, u32, u32)> = read
+        .get_triangles()
+        .iter()
+        .flat_map(|t| t.positions.to_vec())
+        .map(|p| (p.0.to_bits(), p.1.to_bits(), p.2.to_bits()))
+        .collect();
+    corners.sort();
+    corners.dedup();
+    assert_eq!(8, corners.len());
+}